@@ -1,9 +1,9 @@
 #![warn(clippy::all, clippy::pedantic)]
 
 use std::{
-    collections::VecDeque,
-    fs::File,
-    io::{prelude::*, BufReader},
+    collections::{HashMap, VecDeque},
+    fs,
+    hash::Hash,
 };
 
 pub type BoxError = std::boxed::Box<
@@ -12,60 +12,69 @@ pub type BoxError = std::boxed::Box<
         + std::marker::Sync, // needed for threads
 >;
 
+/// Every node reached by [`bfs`] with its distance and the node it came from.
+pub type Visited<N> = HashMap<N, (u64, Option<N>)>;
+
+/// Breadth first search starting from every node in `sources` at once.
+///
+/// Returns every reachable node with its distance from the closest source and
+/// the node it was reached from, sources have no parent.
+pub fn bfs<N, FN, IN>(sources: impl IntoIterator<Item = N>, mut successors: FN) -> Visited<N>
+where
+    N: Eq + Hash + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut seen = HashMap::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if seen.insert(source, (0, None)).is_none() {
+            queue.push_back(source);
+        }
+    }
+    while let Some(cur) = queue.pop_front() {
+        let dist = seen[&cur].0;
+        for next in successors(&cur) {
+            seen.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                (dist + 1, Some(cur))
+            });
+        }
+    }
+    seen
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 struct Square {
     pub height: u8,
     pub pos: (usize, usize),
 }
-impl Square {
-    pub fn _index(&self, grid_size: &(usize, usize)) -> usize {
-        grid_size.1 * self.pos.0 + self.pos.1
-    }
 
+impl Square {
     pub fn can_go_to(&self, other: &Self) -> bool {
-        let from_height = self.height;
-        let to_height = other.height;
-        if from_height + 1 < to_height {
-            return false;
-        }
-        let (from_row, from_col) = self.pos;
-        let (to_row, to_col) = other.pos;
-        (from_row == to_row || from_col == to_col)
-            && from_row + 1 >= to_row
-            && from_row <= to_row + 1
-            && from_col + 1 >= to_col
-            && from_col <= to_col + 1
+        self.height + 1 >= other.height
     }
 }
 
 #[derive(Debug, Default, Clone)]
 struct Game {
     board: Vec<Square>,
-    distances: Vec<Option<u64>>,
+    distances: Visited<(usize, usize)>,
     board_size: (usize, usize),
     start_pos: (usize, usize),
     end_pos: (usize, usize),
 }
 
-impl From<File> for Game {
-    fn from(file: File) -> Self {
-        let buffer = BufReader::new(file);
+impl From<&str> for Game {
+    fn from(input: &str) -> Self {
         let mut board = Vec::new();
         let mut board_size = (0_usize, 0_usize);
         let mut start_pos = (0_usize, 0_usize);
         let mut end_pos = (0_usize, 0_usize);
-        for (row, line) in buffer
-            .lines()
-            .enumerate()
-            .map(|(row, col)| (row, col.unwrap()))
-        {
+        for (row, line) in input.lines().enumerate() {
             board_size.0 += 1;
-            board_size.1 = if board_size.1 == 0 {
-                line.len()
-            } else {
-                board_size.1
-            };
-            for (col, val) in line.as_bytes().iter().enumerate() {
+            board_size.1 = line.len();
+            for (col, val) in line.bytes().enumerate() {
                 let height = match val {
                     b'E' => {
                         end_pos = (row, col);
@@ -83,119 +92,174 @@ impl From<File> for Game {
                 });
             }
         }
-        let mut distances = std::iter::repeat(None)
-            .take(board.len())
-            .collect::<Vec<Option<u64>>>();
-        let index = board_size.1 * end_pos.0 + end_pos.1;
-        distances[index] = Some(0);
         let mut game = Game {
             board,
-            distances,
+            distances: HashMap::new(),
             board_size,
             start_pos,
             end_pos,
         };
-        game._set_distances();
+        // walk backwards from the end so one pass gives the distance from everywhere
+        game.distances = bfs([game.end_pos], |pos| {
+            let to = game.square(*pos);
+            game.get_adjacent_from(*pos)
+                .filter(move |from| from.can_go_to(&to))
+                .map(|from| from.pos)
+        });
         game
     }
 }
 
 impl Game {
-    fn pos_to_index(&self, pos: &(usize, usize)) -> usize {
-        self.board_size.1 * pos.0 + pos.1
+    fn square(&self, pos: (usize, usize)) -> Square {
+        self.board[self.board_size.1 * pos.0 + pos.1]
     }
-    fn _get_adjacent_from(&self, pos: &(usize, usize)) -> Vec<(usize, usize)> {
-        let mut vec = Vec::new();
-        let index = self.pos_to_index(pos);
-        if pos.0 != 0 {
-            let new_pos = (pos.0 - 1, pos.1);
-            let new_index = self.pos_to_index(&new_pos);
-            if self
-                .board
-                .get(new_index)
-                .unwrap()
-                .can_go_to(self.board.get(index).unwrap())
-            {
-                vec.push(new_pos);
-            }
-        }
-        if pos.0 + 1 < self.board_size.0 {
-            let new_pos = (pos.0 + 1, pos.1);
-            let new_index = self.pos_to_index(&new_pos);
-            if self.board[new_index].can_go_to(&self.board[index]) {
-                vec.push(new_pos);
-            }
-        }
-        if pos.1 != 0 {
-            let new_pos = (pos.0, pos.1 - 1);
-            let new_index = self.pos_to_index(&new_pos);
-            if self.board[new_index].can_go_to(&self.board[index]) {
-                vec.push(new_pos);
-            }
-        }
-        if pos.1 + 1 < self.board_size.1 {
-            let new_pos = (pos.0, pos.1 + 1);
-            let new_index = self.pos_to_index(&new_pos);
-            if self.board[new_index].can_go_to(&self.board[index]) {
-                vec.push(new_pos);
-            }
-        }
-        vec
-    }
-    fn _set_distances(&mut self) {
-        let mut queue = VecDeque::new();
-        queue.push_back(self.end_pos);
-        while let Some(cur_pos) = queue.pop_front() {
-            let adjacent = self._get_adjacent_from(&cur_pos);
-            let cur_index = self.pos_to_index(&cur_pos);
-            let cur_dist = self.distances.get(cur_index).unwrap().unwrap();
-            adjacent
-                .iter()
-                .filter(|pos| {
-                    let index = self.pos_to_index(pos);
-                    match self.distances.get(index).unwrap() {
-                        None => {
-                            self.distances[index] = Some(cur_dist + 1);
-                            true
-                        }
-                        Some(check_dist) if *check_dist > cur_dist + 1 => {
-                            self.distances[index] = Some(cur_dist + 1);
-                            true
-                        }
-                        _ => false,
-                    }
-                })
-                .for_each(|check_pos| {
-                    queue.push_back(*check_pos);
-                });
-        }
+
+    fn get_adjacent_from(&self, pos: (usize, usize)) -> impl Iterator<Item = Square> + '_ {
+        [
+            pos.0.checked_sub(1).map(|row| (row, pos.1)),
+            Some((pos.0 + 1, pos.1)),
+            pos.1.checked_sub(1).map(|col| (pos.0, col)),
+            Some((pos.0, pos.1 + 1)),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|(row, col)| *row < self.board_size.0 && *col < self.board_size.1)
+        .map(|pos| self.square(pos))
     }
+
     pub fn get_distance_to_end(&self, pos: &(usize, usize)) -> Option<u64> {
-        *self.distances.get(self.pos_to_index(pos)).unwrap()
+        self.distances.get(pos).map(|(dist, _)| *dist)
     }
-    pub fn get_all_at_height(&self, height: u8) -> Vec<Square> {
+
+    /// The fewest steps to the end from any position at `height`.
+    pub fn shortest_from_height(&self, height: u8) -> Option<u64> {
         self.board
             .iter()
             .filter(|x| x.height == height)
-            .copied()
-            .collect()
+            .filter_map(|x| self.get_distance_to_end(&x.pos))
+            .min()
+    }
+
+    /// One of the shortest paths from `pos` to the end, both included.
+    pub fn path_to_end(&self, pos: &(usize, usize)) -> Option<Vec<(usize, usize)>> {
+        let mut path = vec![*pos];
+        let mut cur = *pos;
+        while let Some(next) = self.distances.get(&cur)?.1 {
+            path.push(next);
+            cur = next;
+        }
+        Some(path)
+    }
+
+    /// Draws `path` the same way as the puzzle, with an arrow on each step.
+    pub fn render_path(&self, path: &[(usize, usize)]) -> String {
+        let mut grid = vec![vec!['.'; self.board_size.1]; self.board_size.0];
+        for step in path.windows(2) {
+            let (from, to) = (step[0], step[1]);
+            grid[from.0][from.1] = match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+                (std::cmp::Ordering::Less, _) => '^',
+                (std::cmp::Ordering::Greater, _) => 'v',
+                (_, std::cmp::Ordering::Less) => '<',
+                _ => '>',
+            };
+        }
+        grid[self.end_pos.0][self.end_pos.1] = 'E';
+        grid.into_iter()
+            .map(|row| row.into_iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
+
 fn main() -> Result<(), BoxError> {
-    let file = File::open("./test.txt")?;
-    let game: Game = file.into();
+    let file = fs::read_to_string("./input.txt")?;
+    let game = Game::from(file.as_str());
     let start = game.start_pos;
     let s = game
         .get_distance_to_end(&start)
         .expect("No end to this game");
+    if std::env::args().any(|arg| arg == "--show") {
+        let path = game.path_to_end(&start).expect("No end to this game");
+        println!("{}", game.render_path(&path));
+    }
     println!("Part 1: {s}");
-    let shortest = game
-        .get_all_at_height(0)
-        .iter()
-        .filter_map(|x| game.get_distance_to_end(&x.pos))
-        .min()
-        .expect("problem");
+    let shortest = game.shortest_from_height(0).expect("problem");
     println!("Part 2: {shortest}");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn part1_works() {
+        let game = Game::from(INPUT);
+        assert_eq!(game.get_distance_to_end(&game.start_pos), Some(31));
+    }
+
+    #[test]
+    fn part2_works() {
+        let game = Game::from(INPUT);
+        assert_eq!(game.shortest_from_height(0), Some(29));
+    }
+
+    #[test]
+    fn bfs_from_many_sources() {
+        let game = Game::from(INPUT);
+        // climbing forwards from every lowest square at once
+        let sources = game.board.iter().filter(|x| x.height == 0).map(|x| x.pos);
+        let visited = bfs(sources, |pos| {
+            let from = game.square(*pos);
+            game.get_adjacent_from(*pos)
+                .filter(move |to| from.can_go_to(to))
+                .map(|to| to.pos)
+        });
+        let sources = game.board.iter().filter(|x| x.height == 0).count();
+        assert_eq!(sources, 6);
+        assert!(game
+            .board
+            .iter()
+            .filter(|x| x.height == 0)
+            .all(|x| visited[&x.pos] == (0, None)));
+        assert_eq!(visited[&game.end_pos].0, 29);
+        assert_eq!(visited[&(0, 2)], (1, Some((0, 1))));
+
+        // following the parents back from the end walks 29 steps to a source
+        let mut cur = game.end_pos;
+        let mut steps = 0;
+        while let (dist, Some(parent)) = visited[&cur] {
+            assert_eq!(visited[&parent].0 + 1, dist);
+            assert!(game.square(parent).can_go_to(&game.square(cur)));
+            cur = parent;
+            steps += 1;
+        }
+        assert_eq!(steps, 29);
+        assert_eq!(game.square(cur).height, 0);
+    }
+
+    #[test]
+    fn path_is_walkable() {
+        let game = Game::from(INPUT);
+        let path = game.path_to_end(&game.start_pos).unwrap();
+        assert_eq!(path.len(), 32);
+        assert_eq!(path.first(), Some(&game.start_pos));
+        assert_eq!(path.last(), Some(&game.end_pos));
+        assert!(path.windows(2).all(|step| {
+            let (from, to) = (game.square(step[0]), game.square(step[1]));
+            from.can_go_to(&to)
+                && from.pos.0.abs_diff(to.pos.0) + from.pos.1.abs_diff(to.pos.1) == 1
+        }));
+        let rendered = game.render_path(&path);
+        assert_eq!(rendered.lines().count(), 5);
+        assert_eq!(rendered.chars().filter(|c| "^v<>".contains(*c)).count(), 31);
+    }
+}
@@ -1,7 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
 use std::{
-    cmp::Ordering::{Equal, Greater, Less},
+    cmp::Ordering::{self, Equal, Greater, Less},
+    fmt::Display,
     fs,
 };
 
@@ -13,6 +14,7 @@ use nom::{
     sequence::{delimited, separated_pair},
     Parser,
 };
+use serde_json::Value;
 
 #[derive(Debug, Eq)]
 pub enum Checker {
@@ -30,42 +32,163 @@ impl Checker {
             delimited(tag("["), separated_list0(tag(","), Self::parse), tag("]")).map(Self::Array),
         ))(input)
     }
+
+    /// Compares two packets using `compare` for the integers, lists are
+    /// compared element by element and a lone integer is treated as a list
+    /// holding just that integer.
+    pub fn cmp_by<F>(&self, other: &Self, mut compare: F) -> Ordering
+    where
+        F: FnMut(u32, u32) -> Ordering,
+    {
+        self.cmp_by_ref(other, &mut compare)
+    }
+
+    fn cmp_by_ref<F>(&self, other: &Self, compare: &mut F) -> Ordering
+    where
+        F: FnMut(u32, u32) -> Ordering,
+    {
+        let (left, right) = match (self, other) {
+            (Self::Num(a), Self::Num(b)) => return compare(*a, *b),
+            (Self::Array(a), Self::Array(b)) => (a.as_slice(), b.as_slice()),
+            (Self::Array(a), Self::Num(_)) => (a.as_slice(), std::slice::from_ref(other)),
+            (Self::Num(_), Self::Array(b)) => (std::slice::from_ref(self), b.as_slice()),
+        };
+        left.iter()
+            .zip(right)
+            .map(|(a, b)| a.cmp_by_ref(b, compare))
+            .find(|ord| ord.is_ne())
+            .unwrap_or_else(|| left.len().cmp(&right.len()))
+    }
+
+    /// # Errors
+    ///
+    /// returns a `CheckerError` if the input isn't json or isn't a packet
+    pub fn from_json(input: &str) -> Result<Self, CheckerError> {
+        let value: Value = serde_json::from_str(input).map_err(CheckerError::Json)?;
+        Self::try_from(&value)
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        self.into()
+    }
 }
 
 impl PartialEq for Checker {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::Num(l0), Self::Num(r0)) => l0 == r0,
-            (Self::Array(l0), Self::Array(r0)) => l0 == r0,
-            (Self::Num(l0), Self::Array(r0)) => &vec![Self::Num(*l0)] == r0,
-            (Self::Array(l0), Self::Num(r0)) => l0 == &vec![Self::Num(*r0)],
-        }
+        self.cmp(other).is_eq()
     }
 }
 
 impl PartialOrd for Checker {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl Ord for Checker {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (Self::Array(a), Self::Array(b)) => a.cmp(b),
-            (Self::Num(a), Self::Num(b)) => a.cmp(b),
-            (Self::Array(a), Self::Num(b)) => a.cmp(&vec![Self::Num(*b)]),
-            (Self::Num(a), Self::Array(b)) => vec![Self::Num(*a)].cmp(b),
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_by(other, |a, b| a.cmp(&b))
+    }
+}
+
+impl Display for Checker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Num(x) => write!(f, "{x}"),
+            Self::Array(list) => {
+                write!(f, "[")?;
+                for (i, item) in list.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CheckerError {
+    Json(serde_json::Error),
+    NotAPacket(Value),
+}
+
+impl Display for CheckerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(err) => write!(f, "packet is not valid json: {err}"),
+            Self::NotAPacket(value) => write!(f, "{value} is not a list or an integer packet"),
         }
     }
 }
 
+impl std::error::Error for CheckerError {}
+
+impl From<&Checker> for Value {
+    fn from(value: &Checker) -> Self {
+        match value {
+            Checker::Num(x) => Self::from(*x),
+            Checker::Array(list) => Self::Array(list.iter().map(Self::from).collect()),
+        }
+    }
+}
+
+impl From<Checker> for Value {
+    fn from(value: Checker) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl TryFrom<&Value> for Checker {
+    type Error = CheckerError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(x) => x
+                .as_u64()
+                .and_then(|x| u32::try_from(x).ok())
+                .map(Self::Num)
+                .ok_or_else(|| CheckerError::NotAPacket(value.clone())),
+            Value::Array(list) => list
+                .iter()
+                .map(Self::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self::Array),
+            _ => Err(CheckerError::NotAPacket(value.clone())),
+        }
+    }
+}
+
+impl TryFrom<Value> for Checker {
+    type Error = CheckerError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
 pub struct Together {
     pub left: Checker,
     pub right: Checker,
 }
 
 impl Together {
+    #[must_use]
+    pub fn is_ordered(&self) -> bool {
+        self.left < self.right
+    }
+
+    /// Checks the pair is in order when the integers are compared with `compare`.
+    pub fn is_ordered_by<F>(&self, compare: F) -> bool
+    where
+        F: FnMut(u32, u32) -> Ordering,
+    {
+        self.left.cmp_by(&self.right, compare).is_lt()
+    }
+
     /// # Errors
     ///
     /// returns an `nom::err::Error<&str>` if there is problems parsing
@@ -82,18 +205,18 @@ pub fn parse_data(input: &str) -> nom::IResult<&str, Vec<Together>, nom::error::
     separated_list1(tag("\n\n"), Together::parse)(input)
 }
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
-    let (_, all) = parse_data(&file).unwrap();
-    let part1 = all
-        .iter()
+fn part1(all: &[Together]) -> usize {
+    all.iter()
         .enumerate()
         .filter_map(|(i, Together { left, right })| match left.cmp(right) {
             Less => Some(i + 1),
             Equal => panic!(),
             Greater => None,
         })
-        .sum::<usize>();
+        .sum::<usize>()
+}
+
+fn part2(all: &[Together]) -> usize {
     let two = Checker::Array(vec![Checker::Array(vec![Checker::Num(2)])]);
     let six = Checker::Array(vec![Checker::Array(vec![Checker::Num(6)])]);
     let mut p2 = all
@@ -113,7 +236,125 @@ fn main() {
         .enumerate()
         .find_map(|(i, b)| if *b == &six { Some(i + 1) } else { None })
         .unwrap();
-    let part2 = i2 * i6;
-    println!("Part 1: {part1}");
-    println!("Part 2: {part2}");
+    i2 * i6
+}
+
+fn main() {
+    let file = fs::read_to_string("./input.txt").unwrap();
+    let (_, all) = parse_data(&file).unwrap();
+    println!("Part 1: {}", part1(&all));
+    println!("Part 2: {}", part2(&all));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    const INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    fn packet() -> impl Strategy<Value = Checker> {
+        any::<u32>()
+            .prop_map(Checker::Num)
+            .prop_recursive(4, 64, 8, |inner| {
+                prop::collection::vec(inner, 0..8).prop_map(Checker::Array)
+            })
+    }
+
+    #[test]
+    fn part1_works() {
+        let (_, all) = parse_data(INPUT).unwrap();
+        assert_eq!(part1(&all), 13);
+    }
+
+    #[test]
+    fn part2_works() {
+        let (_, all) = parse_data(INPUT).unwrap();
+        assert_eq!(part2(&all), 140);
+    }
+
+    #[test]
+    fn custom_comparator() {
+        let (_, all) = parse_data(INPUT).unwrap();
+        let ordered = all.iter().map(Together::is_ordered).collect::<Vec<_>>();
+        assert_eq!(
+            ordered,
+            [true, true, false, true, false, true, false, false]
+        );
+        // flipping the integer comparison only changes pairs decided by integers
+        let flipped = all
+            .iter()
+            .map(|pair| pair.is_ordered_by(|a, b| b.cmp(&a)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            flipped,
+            [false, false, true, true, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn json_rejects_non_packets() {
+        assert!(matches!(
+            Checker::from_json("[1,\"a\"]"),
+            Err(CheckerError::NotAPacket(_))
+        ));
+        assert!(matches!(
+            Checker::from_json("[-1]"),
+            Err(CheckerError::NotAPacket(_))
+        ));
+        assert!(matches!(
+            Checker::from_json("[1,"),
+            Err(CheckerError::Json(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn nom_and_json_agree(packet in packet()) {
+            let text = packet.to_string();
+            let (rest, from_nom) = Checker::parse(&text).unwrap();
+            prop_assert!(rest.is_empty());
+            let from_json = Checker::from_json(&text).unwrap();
+            prop_assert_eq!(from_nom.to_json(), from_json.to_json());
+            prop_assert_eq!(from_json.to_json().to_string(), text);
+        }
+
+        #[test]
+        fn json_round_trips(packet in packet()) {
+            let value = packet.to_json();
+            let back = Checker::try_from(&value).unwrap();
+            prop_assert_eq!(back.to_json(), value);
+        }
+
+        #[test]
+        fn ordering_matches_reversed(a in packet(), b in packet()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(
+                a.cmp_by(&b, |x, y| y.cmp(&x)),
+                b.cmp_by(&a, |x, y| y.cmp(&x)).reverse()
+            );
+        }
+    }
 }
@@ -1,6 +1,6 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use std::{collections::HashMap, fmt::Display, fs};
+use std::{fmt::Display, fs};
 
 use nom::{
     bytes::complete::tag,
@@ -9,8 +9,9 @@ use nom::{
     sequence::separated_pair,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 enum Square {
+    #[default]
     Air,
     Wall,
     Sand,
@@ -20,16 +21,11 @@ impl Square {
     pub fn is_sand(self) -> bool {
         matches!(self, Self::Sand)
     }
-    pub fn is_rock(self) -> bool {
-        matches!(self, Self::Wall)
+    pub fn is_air(self) -> bool {
+        matches!(self, Self::Air)
     }
 }
 
-impl Default for Square {
-    fn default() -> Self {
-        Self::Air
-    }
-}
 impl Display for Square {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let display = match self {
@@ -41,22 +37,179 @@ impl Display for Square {
     }
 }
 
-fn rock_wall(input: &str) -> nom::IResult<&str, HashMap<(usize, usize), Square>> {
+/// A cave stored as a dense grid of squares.
+///
+/// `path` holds the squares the last grain fell through, so the next grain
+/// starts falling from where the previous one came to rest instead of from
+/// the source.
+#[derive(Debug, Clone)]
+struct Cave {
+    grid: Vec<Square>,
+    min_x: usize,
+    width: usize,
+    height: usize,
+    source: (usize, usize),
+    floor: Option<usize>,
+    path: Vec<(usize, usize)>,
+}
+
+impl Cave {
+    /// Builds a cave from the rock points, with sand pouring in at `source`.
+    ///
+    /// With `floor_offset` set there is an infinite floor that many squares
+    /// below the lowest rock, otherwise sand falls into the abyss. When the
+    /// floor is deep enough for sand to spread past `x = 0` everything is
+    /// moved right so the sand always has a column to fall into.
+    pub fn new(
+        rocks: &[(usize, usize)],
+        source: (usize, usize),
+        floor_offset: Option<usize>,
+    ) -> Self {
+        let lowest = rocks
+            .iter()
+            .map(|(_, y)| *y)
+            .chain([source.1])
+            .max()
+            .unwrap_or_default();
+        let floor = floor_offset.map(|offset| lowest + offset);
+        // sand can never get further from the source than the cave is deep
+        let spread = floor.map_or(0, |floor| floor - source.1);
+        let shift = spread.saturating_sub(source.0);
+        let rocks = rocks
+            .iter()
+            .map(|(x, y)| (x + shift, *y))
+            .collect::<Vec<_>>();
+        let source = (source.0 + shift, source.1);
+        let (min_x, max_x) = if floor.is_some() {
+            (source.0 - spread, source.0 + spread)
+        } else {
+            rocks
+                .iter()
+                .map(|(x, _)| *x)
+                .chain([source.0])
+                .fold((usize::MAX, 0), |(min, max), x| (min.min(x), max.max(x)))
+        };
+        let width = max_x - min_x + 1;
+        let height = floor.unwrap_or(lowest + 1);
+        let mut cave = Self {
+            grid: vec![Square::Air; width * height],
+            min_x,
+            width,
+            height,
+            source,
+            floor,
+            path: Vec::new(),
+        };
+        for pt in rocks {
+            if let Some(index) = cave.index(pt) {
+                cave.grid[index] = Square::Wall;
+            }
+        }
+        cave
+    }
+
+    fn index(&self, (x, y): (usize, usize)) -> Option<usize> {
+        if x < self.min_x || x >= self.min_x + self.width || y >= self.height {
+            return None;
+        }
+        Some(y * self.width + x - self.min_x)
+    }
+
+    pub fn get(&self, pt: (usize, usize)) -> Square {
+        if self.floor.is_some_and(|floor| pt.1 >= floor) {
+            return Square::Wall;
+        }
+        self.index(pt).map_or(Square::Air, |index| self.grid[index])
+    }
+
+    /// Drops one grain of sand, returns `false` if it fell into the abyss or
+    /// the source is already blocked.
+    pub fn drop_grain(&mut self) -> bool {
+        if !self.get(self.source).is_air() {
+            return false;
+        }
+        if self.path.is_empty() {
+            self.path.push(self.source);
+        }
+        while let Some(&(x, y)) = self.path.last() {
+            let next = [Some(x), x.checked_sub(1), Some(x + 1)]
+                .into_iter()
+                .map(|next_x| next_x.map(|next_x| (next_x, y + 1)))
+                .find(|pt| pt.is_none_or(|pt| self.get(pt).is_air()));
+            match next {
+                Some(Some(pt)) if self.index(pt).is_some() => self.path.push(pt),
+                // the floor leaves room either side of anywhere sand can get
+                Some(_) if self.floor.is_some() => unreachable!("sand fell past the floor"),
+                // left the grid without hitting anything, so into the abyss
+                Some(_) => return false,
+                None => {
+                    let index = self.index((x, y)).expect("sand to rest in the cave");
+                    self.grid[index] = Square::Sand;
+                    self.path.pop();
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Drops sand until no more will come to rest.
+    pub fn fill(&mut self) -> usize {
+        while self.drop_grain() {}
+        self.sand_count()
+    }
+
+    pub fn sand_count(&self) -> usize {
+        self.grid.iter().filter(|x| x.is_sand()).count()
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // only show the columns that have something in them
+        let (first, last) = (0..self.width)
+            .filter(|col| {
+                col + self.min_x == self.source.0
+                    || (0..self.height).any(|row| !self.grid[row * self.width + col].is_air())
+            })
+            .fold((usize::MAX, 0), |(min, max), col| {
+                (min.min(col), max.max(col))
+            });
+        for row in 0..self.height {
+            for col in first..=last {
+                if (col + self.min_x, row) == self.source
+                    && self.grid[row * self.width + col].is_air()
+                {
+                    write!(f, "+")?;
+                } else {
+                    write!(f, "{}", self.grid[row * self.width + col])?;
+                }
+            }
+            writeln!(f)?;
+        }
+        if self.floor.is_some() {
+            writeln!(f, "{}", "#".repeat(last - first + 1))?;
+        }
+        Ok(())
+    }
+}
+
+fn rock_wall(input: &str) -> nom::IResult<&str, Vec<(usize, usize)>> {
     let (input, pts) = separated_list0(
         tag(" -> "),
         separated_pair(complete::u64, tag(","), complete::u64),
     )(input)?;
-    let mut rocks = HashMap::new();
-    pts.iter()
-        .enumerate()
-        .take(pts.len() - 1)
-        .flat_map(|(i, start)| {
+    let rocks = pts
+        .windows(2)
+        .flat_map(|pair| {
             let start = (
-                usize::try_from(start.0).unwrap(),
-                usize::try_from(start.1).unwrap(),
+                usize::try_from(pair[0].0).unwrap(),
+                usize::try_from(pair[0].1).unwrap(),
+            );
+            let end = (
+                usize::try_from(pair[1].0).unwrap(),
+                usize::try_from(pair[1].1).unwrap(),
             );
-            let (ex, ey) = pts.get(i + 1).unwrap();
-            let end = (usize::try_from(*ex).unwrap(), usize::try_from(*ey).unwrap());
             if start.0 == end.0 {
                 let (s, e) = if start.1 < end.1 {
                     (start.1, end.1)
@@ -75,73 +228,124 @@ fn rock_wall(input: &str) -> nom::IResult<&str, HashMap<(usize, usize), Square>>
                 panic!("wee wooo");
             }
         })
-        .for_each(|pt| {
-            rocks.insert(pt, Square::Wall);
-        });
+        .collect();
     Ok((input, rocks))
 }
 
-fn parse_input(input: &str) -> nom::IResult<&str, HashMap<(usize, usize), Square>> {
+fn parse_input(input: &str) -> nom::IResult<&str, Vec<(usize, usize)>> {
     let (input, walls) = separated_list0(newline, rock_wall)(input)?;
-    let walls = walls
-        .iter()
-        .flatten()
-        .map(|(pt, s)| (*pt, *s))
-        .collect::<HashMap<(usize, usize), Square>>();
-    Ok((input, walls))
+    Ok((input, walls.into_iter().flatten().collect()))
+}
+
+fn part1(input: &str) -> (usize, Cave) {
+    let (_, rocks) = parse_input(input).unwrap();
+    let mut cave = Cave::new(&rocks, (500, 0), None);
+    (cave.fill(), cave)
+}
+
+fn part2(input: &str) -> (usize, Cave) {
+    let (_, rocks) = parse_input(input).unwrap();
+    let mut cave = Cave::new(&rocks, (500, 0), Some(2));
+    (cave.fill(), cave)
 }
 
 fn main() {
     let file = fs::read_to_string("./input.txt").unwrap();
-    let (_, mut board) = parse_input(&file).unwrap();
-
-    //insert rocks
-    //get lowest rock
-    let lowest = board
-        .iter()
-        .filter_map(|((_, y), typ)| if typ.is_rock() { Some(y) } else { None })
-        .max()
-        .unwrap()
-        .to_owned();
-    while board.get(&(500_usize, 0_usize)).is_none() {
-        let mut square = (500_usize, 0_usize);
-        //drop - find the place to insert
-        while square.1 < lowest {
-            let checks = [
-                (square.0, square.1 + 1),
-                (square.0 - 1, square.1 + 1),
-                (square.0 + 1, square.1 + 1),
-            ];
-            square = if let Some(new) = checks.iter().find(|pos| board.get(pos).is_none()) {
-                *new
-            } else {
-                break;
-            };
-        }
-        if square.1 >= lowest {
-            break;
-        }
-        board.insert(square, Square::Sand);
+    let show = std::env::args().any(|arg| arg == "--show");
+
+    let (part1, cave) = part1(&file);
+    if show {
+        println!("{cave}");
     }
-    let part1 = board.values().filter(|x| x.is_sand()).count();
     println!("Part 1: {part1}");
-    while board.get(&(500_usize, 0_usize)).is_none() {
-        let mut square = (500_usize, 0_usize);
-        //drop - find the place to insert
-        while square.1 < lowest + 1 {
-            let checks = [
-                (square.0, square.1 + 1),
-                (square.0 - 1, square.1 + 1),
-                (square.0 + 1, square.1 + 1),
-            ];
-            square = if let Some(new) = checks.iter().find(|pos| board.get(pos).is_none()) {
-                *new
-            } else {
-                break;
-            };
-        }
-        board.insert(square, Square::Sand);
+    let (part2, cave) = part2(&file);
+    if show {
+        println!("{cave}");
     }
-    let part2 = board.values().filter(|x| x.is_sand()).count();
     println!("Part 2: {part2}");
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    const PART2_CAVE: &str = "..........o..........
+.........ooo.........
+........ooooo........
+.......ooooooo.......
+......oo#ooo##o......
+.....ooo#ooo#ooo.....
+....oo###ooo#oooo....
+...oooo.oooo#ooooo...
+..oooooooooo#oooooo..
+.ooo#########ooooooo.
+ooooo.......ooooooooo
+#####################
+";
+
+    #[test]
+    fn part1_works() {
+        let (count, cave) = part1(INPUT);
+        assert_eq!(count, 24);
+        assert_eq!(
+            cave.to_string(),
+            "......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
+    }
+
+    #[test]
+    fn part2_works() {
+        let (count, cave) = part2(INPUT);
+        assert_eq!(count, 93);
+        assert_eq!(cave.to_string(), PART2_CAVE);
+    }
+
+    #[test]
+    fn source_and_floor_are_configurable() {
+        let (_, rocks) = parse_input(INPUT).unwrap();
+        let mut cave = Cave::new(&rocks, (500, 0), Some(1));
+        // floor sits right under the lowest rock so sand builds a shorter pile
+        assert_eq!(cave.fill(), 79);
+        assert_eq!(
+            cave.to_string(),
+            ".........o.........
+........ooo........
+.......ooooo.......
+......ooooooo......
+.....oo#ooo##o.....
+....ooo#ooo#ooo....
+...oo###ooo#oooo...
+..oooo.oooo#ooooo..
+.oooooooooo#oooooo.
+ooo#########ooooooo
+###################
+"
+        );
+        // nothing under this source so it all falls into the abyss
+        let mut cave = Cave::new(&rocks, (510, 0), None);
+        assert_eq!(cave.fill(), 0);
+    }
+
+    #[test]
+    fn floor_reaches_past_the_left_edge() {
+        let (_, rocks) = parse_input(INPUT).unwrap();
+        let rocks = rocks.iter().map(|(x, y)| (x - 494, *y)).collect::<Vec<_>>();
+        // the pile spreads 11 squares either side of the source at x = 6
+        let mut cave = Cave::new(&rocks, (6, 0), Some(2));
+        assert_eq!(cave.fill(), 93);
+        assert_eq!(cave.to_string(), PART2_CAVE);
+    }
+}
@@ -9,9 +9,6 @@ use nom::{
     multi::separated_list1,
 };
 
-trait Pos {
-    fn get_pos(self) -> (i64, i64);
-}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Sensor {
     pub x: i64,
@@ -20,6 +17,7 @@ pub struct Sensor {
 }
 
 impl Sensor {
+    #[must_use]
     pub fn x_covereage_at_y(&self, y: i64) -> Option<RangeInclusive<i64>> {
         let dist = (y - self.y).abs();
         let n = self.strength - dist;
@@ -28,6 +26,46 @@ impl Sensor {
         }
         Some((self.x - n)..=(self.x + n))
     }
+    #[must_use]
+    pub fn covers(&self, x: i64, y: i64) -> bool {
+        (x - self.x).abs() + (y - self.y).abs() <= self.strength
+    }
+    #[must_use]
+    pub fn y_coverage_at_x(&self, x: i64) -> Option<RangeInclusive<i64>> {
+        let n = self.strength - (x - self.x).abs();
+        (n >= 0).then(|| (self.y - n)..=(self.y + n))
+    }
+    /// The `x` values covered along the line `x + y = u`.
+    #[must_use]
+    pub fn x_coverage_on_u(&self, u: i64) -> Option<RangeInclusive<i64>> {
+        self.coverage_along(u - self.x - self.y)
+            .map(|a_s| (self.x + a_s.start())..=(self.x + a_s.end()))
+    }
+    /// The `x` values covered along the line `x - y = v`.
+    #[must_use]
+    pub fn x_coverage_on_v(&self, v: i64) -> Option<RangeInclusive<i64>> {
+        self.coverage_along(v - self.x + self.y)
+            .map(|a_s| (self.x + a_s.start())..=(self.x + a_s.end()))
+    }
+    /// Offsets `a` from the sensor's `x` with `|a| + |d - a|` in range, which is
+    /// the distance to a square on a diagonal `d` away from the sensor's own.
+    fn coverage_along(&self, d: i64) -> Option<RangeInclusive<i64>> {
+        (d.abs() <= self.strength)
+            .then(|| -(self.strength - d).div_euclid(2)..=(d + self.strength).div_euclid(2))
+    }
+    /// The lines running just outside the edge of the covered diamond, in
+    /// rotated coordinates: `u = x + y` for the two rising edges and
+    /// `v = x - y` for the two falling ones.
+    #[must_use]
+    pub fn boundary_lines(&self) -> ([i64; 2], [i64; 2]) {
+        let (u, v) = (self.x + self.y, self.x - self.y);
+        let reach = self.strength + 1;
+        ([u - reach, u + reach], [v - reach, v + reach])
+    }
+    /// # Panics
+    ///
+    /// panics if the coverage calculation is off
+    #[must_use]
     pub fn coverage(&self) -> Vec<(i64, RangeInclusive<i64>)> {
         ((self.y - self.strength)..=(self.y + self.strength))
             .map(|y| {
@@ -39,22 +77,12 @@ impl Sensor {
             .collect()
     }
 }
-impl Pos for Sensor {
-    fn get_pos(self) -> (i64, i64) {
-        (self.x, self.y)
-    }
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Beacon {
     pub x: i64,
     pub y: i64,
 }
-impl Pos for Beacon {
-    fn get_pos(self) -> (i64, i64) {
-        (self.x, self.y)
-    }
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Square {
@@ -63,6 +91,7 @@ pub enum Square {
     Sensor(Sensor),
 }
 impl Square {
+    #[must_use]
     pub fn is_covered(&self) -> bool {
         matches!(self, Self::Covered)
     }
@@ -138,6 +167,8 @@ fn parse_output(input: &str) -> nom::IResult<&str, Vec<Square>> {
     Ok((input, output))
 }
 
+const TUNING_MULTIPLIER: i64 = 4_000_000;
+
 fn coverage_count_at_y(board: &[Square], y: i64) -> Vec<i64> {
     board
         .iter()
@@ -150,6 +181,7 @@ fn coverage_count_at_y(board: &[Square], y: i64) -> Vec<i64> {
         .unique()
         .collect()
 }
+#[must_use]
 pub fn part1(board: &[Square], y: i64) -> String {
     let pos_covered_on_y = coverage_count_at_y(board, y).len();
     let obs_on_y = board
@@ -176,6 +208,10 @@ pub fn part1(board: &[Square], y: i64) -> String {
     (pos_covered_on_y - obs_on_y).to_string()
 }
 
+/// # Panics
+///
+/// panics if every square within the bounds is covered by a sensor
+#[must_use]
 pub fn part2(board: &[Square], lower: i64, upper: i64) -> String {
     let bb = board
         .iter()
@@ -219,23 +255,91 @@ pub fn part2(board: &[Square], lower: i64, upper: i64) -> String {
                 .map(|x| (x, y))
         })
         .unwrap();
-    (4_000_000 * x + y).to_string()
+    (TUNING_MULTIPLIER * x + y).to_string()
+}
+
+/// Finds the distress beacon along the lines just outside the sensor ranges.
+#[must_use]
+pub fn part2_lines(board: &[Square], lower: i64, upper: i64) -> Option<String> {
+    find_uncovered(board, lower, upper).map(|(x, y)| (TUNING_MULTIPLIER * x + y).to_string())
+}
+
+/// Finds a square within the bounds that no sensor covers.
+///
+/// Take the uncovered square furthest right. The square to its right is
+/// either past the `upper` bound or covered by a sensor it is just out of
+/// reach of, which puts it on one of that sensor's `u` or `v` boundary lines.
+/// Walking those lines and the right hand edge is enough to find one, so the
+/// work depends on the number of sensors rather than the size of the area.
+#[must_use]
+pub fn find_uncovered(board: &[Square], lower: i64, upper: i64) -> Option<(i64, i64)> {
+    let sensors = board
+        .iter()
+        .filter_map(|square| match square {
+            Square::Sensor(x) => Some(x),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let (us, vs): (Vec<_>, Vec<_>) = sensors.iter().map(|s| s.boundary_lines()).unzip();
+
+    let on_rising = us.into_iter().flatten().unique().find_map(|u| {
+        let x_s = lower.max(u - upper)..=upper.min(u - lower);
+        first_gap(x_s, sensors.iter().filter_map(|s| s.x_coverage_on_u(u))).map(|x| (x, u - x))
+    });
+    let on_falling = || {
+        vs.into_iter().flatten().unique().find_map(|v| {
+            let x_s = lower.max(v + lower)..=upper.min(v + upper);
+            first_gap(x_s, sensors.iter().filter_map(|s| s.x_coverage_on_v(v))).map(|x| (x, x - v))
+        })
+    };
+    let on_edge = || {
+        first_gap(
+            lower..=upper,
+            sensors.iter().filter_map(|s| s.y_coverage_at_x(upper)),
+        )
+        .map(|y| (upper, y))
+    };
+    on_rising.or_else(on_falling).or_else(on_edge)
+}
+
+/// Finds the first value in `span` that none of the `covered` ranges reach.
+fn first_gap(
+    span: RangeInclusive<i64>,
+    covered: impl Iterator<Item = RangeInclusive<i64>>,
+) -> Option<i64> {
+    let mut next = *span.start();
+    for range in covered.sorted_by_key(|range| *range.start()) {
+        if *range.start() > next {
+            break;
+        }
+        next = next.max(range.end() + 1);
+    }
+    (next <= *span.end()).then_some(next)
 }
 
 fn main() {
     let file = fs::read_to_string("./input.txt").unwrap();
     let (_, board) = parse_output(&file).unwrap();
 
-    let y = 2_000_000;
+    // row to check and the upper search bound can be given on the command line
+    let mut args = std::env::args()
+        .skip(1)
+        .map(|arg| arg.parse::<i64>().expect("bounds to be numbers"));
+    let y = args.next().unwrap_or(2_000_000);
+    let upper = args.next().unwrap_or(4_000_000);
 
     println!("Part 1: {}", part1(&board, y));
 
-    println!("Part 2: {}", part2(&board, 0, 4_000_000));
+    match part2_lines(&board, 0, upper) {
+        Some(part2) => println!("Part 2: {part2}"),
+        None => println!("Part 2: no uncovered square"),
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
 
     const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
@@ -263,4 +367,75 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3";
         let (_, board) = parse_output(INPUT).unwrap();
         assert_eq!(part2(&board, 0, 20), "56000011");
     }
+
+    #[test]
+    fn part2_lines_works() {
+        let (_, board) = parse_output(INPUT).unwrap();
+        assert_eq!(part2_lines(&board, 0, 20).as_deref(), Some("56000011"));
+    }
+
+    #[test]
+    fn find_uncovered_matches_scan() {
+        let (_, board) = parse_output(INPUT).unwrap();
+        let sensors = board
+            .iter()
+            .filter_map(|square| match square {
+                Square::Sensor(x) => Some(*x),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // smaller areas leave uncovered squares on the edges rather than
+        // between two sensors, and sometimes none at all
+        for lower in -6..=24 {
+            for upper in lower..=lower + 5 {
+                let uncovered = (lower..=upper)
+                    .cartesian_product(lower..=upper)
+                    .filter(|(x, y)| !sensors.iter().any(|s| s.covers(*x, *y)))
+                    .collect::<Vec<_>>();
+                match find_uncovered(&board, lower, upper) {
+                    Some(found) => assert!(uncovered.contains(&found), "{lower}..={upper}"),
+                    None => assert!(uncovered.is_empty(), "{lower}..={upper}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn find_uncovered_between_sensors() {
+        let sensors = [
+            (8, 0, 5),
+            (6, 0, 6),
+            (3, 2, 1),
+            (6, 8, 6),
+            (0, 0, 5),
+            (1, 6, 1),
+            (7, 7, 7),
+        ]
+        .map(|(x, y, strength)| Square::Sensor(Sensor { x, y, strength }));
+        assert_eq!(find_uncovered(&sensors, 0, 8), Some((2, 4)));
+    }
+
+    fn sensor() -> impl Strategy<Value = Sensor> {
+        (-2_i64..12, -2_i64..12, 0_i64..9).prop_map(|(x, y, strength)| Sensor { x, y, strength })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(10_000))]
+
+        #[test]
+        fn find_uncovered_matches_brute_force(
+            upper in 1_i64..=10,
+            sensors in prop::collection::vec(sensor(), 1..=8),
+        ) {
+            let board = sensors.iter().map(Square::from).collect::<Vec<_>>();
+            let uncovered = (0..=upper)
+                .cartesian_product(0..=upper)
+                .filter(|(x, y)| !sensors.iter().any(|s| s.covers(*x, *y)))
+                .collect::<Vec<_>>();
+            match find_uncovered(&board, 0, upper) {
+                Some(found) => prop_assert!(uncovered.contains(&found)),
+                None => prop_assert!(uncovered.is_empty()),
+            }
+        }
+    }
 }
@@ -5,6 +5,7 @@ use std::{
     fs,
 };
 
+use aoc_dot::{Edge, Node, ToDot};
 use itertools::Itertools;
 use nom::{
     branch::alt,
@@ -37,7 +38,7 @@ impl<'a> Parser<&'a str, Self, Error<&'a str>> for Valve {
         )(input)?;
         self.label = label;
         self.release = release;
-        self.connected_to = connected_to.clone();
+        self.connected_to.clone_from(&connected_to);
         Ok((input, self.clone()))
     }
 }
@@ -54,6 +55,55 @@ fn parse_input(input: &str) -> nom::IResult<&str, HashMap<String, Valve>> {
     ))
 }
 
+/// The valves and the tunnels between them, for drawing.
+struct Tunnels<'a>(&'a HashMap<String, Valve>);
+
+/// Valves with a flow rate are double circles labeled with the rate, and
+/// each tunnel is drawn once even though both ends list it.
+impl ToDot for Tunnels<'_> {
+    fn directed(&self) -> bool {
+        false
+    }
+
+    fn dot_nodes(&self) -> Vec<Node> {
+        self.0
+            .values()
+            .sorted_by(|a, b| a.label.cmp(&b.label))
+            .map(|valve| {
+                if valve.release > 0 {
+                    Node::new(&valve.label)
+                        .label(format!("{}\nrate={}", valve.label, valve.release))
+                        .shape("doublecircle")
+                } else {
+                    Node::new(&valve.label)
+                }
+            })
+            .collect()
+    }
+
+    fn dot_edges(&self) -> Vec<Edge> {
+        self.0
+            .values()
+            .flat_map(|valve| {
+                valve
+                    .connected_to
+                    .iter()
+                    .filter(|to| {
+                        // tunnels listed from both ends are drawn from the lower label
+                        valve.label < **to
+                            || self
+                                .0
+                                .get(*to)
+                                .is_none_or(|to| !to.connected_to.contains(&valve.label))
+                    })
+                    .map(|to| (valve.label.clone(), to.clone()))
+            })
+            .sorted()
+            .map(|(from, to)| Edge::new(from, to))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 struct SimpleValve {
     pub _label: String,
@@ -195,7 +245,7 @@ fn part2(input: &str) -> String {
     let board = convert_to_distance(&parse_input(input).unwrap().1);
     let cache = &mut HashMap::new();
     let top_mask: usize = (1 << board.len()) - 1;
-    (0..=((top_mask + 1) / 2))
+    (0..=top_mask.div_ceil(2))
         .map(|i| {
             recurse(&board, 0, Pathmask::from(i), 26, 0, cache)
                 + recurse(&board, 0, Pathmask::from(top_mask ^ i), 26, 0, cache)
@@ -207,6 +257,11 @@ fn part2(input: &str) -> String {
 //if open don't close
 fn main() {
     let file = fs::read_to_string("input.txt").unwrap();
+    if let Some(path) = aoc_dot::path_from_args() {
+        Tunnels(&parse_input(&file).unwrap().1)
+            .write_dot(path)
+            .unwrap();
+    }
     println!("Part 1: {}", part1(&file));
     println!("Part 2: {}", part2(&file));
 }
@@ -228,18 +283,40 @@ Valve JJ has flow rate=21; tunnel leads to valve II";
 
     #[test]
     fn part1_works() {
-        assert_eq!(part1(INPUT), "1651")
+        assert_eq!(part1(INPUT), "1651");
     }
 
     #[test]
     fn part2_works() {
-        assert_eq!(part2(INPUT), "1707")
+        assert_eq!(part2(INPUT), "1707");
     }
+    #[test]
+    fn tunnels_to_dot() {
+        let (_, valves) = parse_input(
+            "Valve AA has flow rate=0; tunnels lead to valves BB, CC
+Valve BB has flow rate=13; tunnel leads to valve AA
+Valve CC has flow rate=0; tunnel leads to valve BB",
+        )
+        .unwrap();
+        assert_eq!(
+            Tunnels(&valves).to_dot(),
+            r#"graph {
+    "AA";
+    "BB" [label="BB\nrate=13", shape="doublecircle"];
+    "CC";
+    "AA" -- "BB";
+    "AA" -- "CC";
+    "CC" -- "BB";
+}
+"#
+        );
+    }
+
     #[test]
     fn testssss() {
         let board = convert_to_distance(&parse_input(INPUT).unwrap().1);
         let top_mask: usize = (1 << board.len()) - 1;
-        (0..=((top_mask + 1) / 2)).for_each(|i| {
+        (0..=top_mask.div_ceil(2)).for_each(|i| {
             let other = i ^ top_mask;
             let test = i | other;
 
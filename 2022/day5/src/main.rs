@@ -1,33 +1,16 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::fmt::Display;
 use std::fs;
 
-use nom::branch::alt;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::{tag, take_until};
 use nom::character::complete::{self, newline};
-use nom::error::Error;
+use nom::error::{Error, ErrorKind};
 use nom::multi::separated_list1;
-use nom::sequence::{delimited, preceded, separated_pair};
+use nom::sequence::{preceded, separated_pair, terminated};
 use nom::Parser;
 
-#[derive(Clone, Debug, Default, Copy)]
-struct Crate<'a> {
-    label: &'a str,
-}
-
-impl<'a> Parser<&'a str, Self, Error<&'a str>> for Crate<'a> {
-    fn parse(&mut self, input: &'a str) -> nom::IResult<&'a str, Self, Error<&'a str>> {
-        let (input, label) = delimited::<&str, _, &str, _, Error<&'a str>, _, _, _>(
-            tag("["),
-            complete::alpha1,
-            tag("]"),
-        )(input)?;
-        self.label = label;
-        Ok((input, *self))
-    }
-}
-
-#[derive(Clone, Debug, Default, Copy)]
+#[derive(Clone, Debug, Default, Copy, PartialEq, Eq)]
 struct GameMove {
     quantity: usize,
     from: usize,
@@ -35,118 +18,260 @@ struct GameMove {
 }
 impl<'a> Parser<&'a str, Self, Error<&'a str>> for GameMove {
     fn parse(&mut self, input: &'a str) -> nom::IResult<&'a str, Self, Error<&'a str>> {
-        let (input, quantity) = preceded(tag("move "), complete::u8.map(|x| x as usize))(input)?;
-        let (input, from) = preceded(tag(" from "), complete::u8.map(|x| x as usize))(input)?;
-        let (input, to) = preceded(tag(" to "), complete::u8.map(|x| x as usize))(input)?;
+        let (input, quantity) = preceded(tag("move "), complete::u32.map(|x| x as usize))(input)?;
+        let (input, from) = preceded(tag(" from "), complete::u32.map(|x| x as usize))(input)?;
+        let (input, to) = preceded(tag(" to "), complete::u32.map(|x| x as usize))(input)?;
         self.quantity = quantity;
         self.from = from;
         self.to = to;
         Ok((input, *self))
     }
 }
-
-#[derive(Debug, Default)]
-struct GameBoard<'a> {
-    _labels: Vec<String>,
-    board: Vec<Vec<Crate<'a>>>,
+impl Display for GameMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity, self.from, self.to
+        )
+    }
 }
 
-impl<'a> GameBoard<'a> {
-    pub fn game1_move(&mut self, m: &GameMove) {
-        let v = &mut Vec::new();
-        let work = self.board.get_mut(m.from - 1).unwrap();
-        for _ in 0..m.quantity {
-            v.push(work.pop().unwrap());
-        }
-        let work = self.board.get_mut(m.to - 1).unwrap();
-        for _ in 0..m.quantity {
-            work.append(v);
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MoveError {
+    UnknownStack(usize),
+    NotEnoughCrates(GameMove),
+}
+impl Display for MoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownStack(label) => write!(f, "there is no stack labeled {label}"),
+            Self::NotEnoughCrates(m) => write!(f, "not enough crates to {m}"),
         }
     }
-    pub fn game2_move(&mut self, m: &GameMove) {
-        let v = &mut Vec::new();
-        let work = self.board.get_mut(m.from - 1).unwrap();
-        for _ in 0..m.quantity {
-            v.push(work.pop().unwrap());
+}
+impl std::error::Error for MoveError {}
+
+/// How a crane sets down the crates it lifted off a stack.
+trait CraneModel {
+    /// `lifted` comes off the stack in order, so the old top crate is last,
+    /// and is rearranged into the order the crates end up stacked in.
+    fn set_down(&self, lifted: &mut [String]);
+}
+
+/// Moves one crate at a time, so the lifted crates end up reversed.
+struct CrateMover9000;
+impl CraneModel for CrateMover9000 {
+    fn set_down(&self, lifted: &mut [String]) {
+        lifted.reverse();
+    }
+}
+
+/// Moves all the crates at once, so they keep their order.
+struct CrateMover9001;
+impl CraneModel for CrateMover9001 {
+    fn set_down(&self, _lifted: &mut [String]) {}
+}
+
+/// Splits a line into its words along with where they start and end.
+fn words(line: &str) -> impl Iterator<Item = (usize, usize, &str)> {
+    line.split(' ')
+        .scan(0, |start, word| {
+            let pos = *start;
+            *start += word.len() + 1;
+            Some((pos, pos + word.len(), word))
+        })
+        .filter(|(_, _, word)| !word.is_empty())
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Stacks {
+    labels: Vec<String>,
+    stacks: Vec<Vec<String>>,
+}
+
+impl Stacks {
+    /// Parses the drawing and the line of stack labels under it. Each crate
+    /// goes on the stack whose label is closest to being underneath it, so
+    /// wide labels and any number of stacks are fine.
+    fn parse(input: &str) -> nom::IResult<&str, Self> {
+        let (rest, drawing) = terminated(take_until("\n\n"), newline)(input)?;
+        let mut lines = drawing.lines().rev();
+        let labels = lines
+            .next()
+            .map(|line| words(line).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if labels.is_empty() {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::Verify)));
         }
-        v.reverse();
-        let work = self.board.get_mut(m.to - 1).unwrap();
-        for _ in 0..m.quantity {
-            work.append(v);
+        let mut stacks = vec![Vec::new(); labels.len()];
+        for line in lines {
+            for (start, end, word) in words(line) {
+                let label = word.strip_prefix('[').and_then(|x| x.strip_suffix(']'));
+                let Some(label) = label else {
+                    return Err(nom::Err::Error(Error::new(line, ErrorKind::Verify)));
+                };
+                // comparing start + end saves halving them to get the middle
+                let middle = start + end;
+                let stack = labels
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, (l_start, l_end, _))| (l_start + l_end).abs_diff(middle))
+                    .map(|(i, _)| i)
+                    .unwrap();
+                stacks[stack].push(label.to_string());
+            }
         }
+        let labels = labels.iter().map(|(_, _, x)| (*x).to_string()).collect();
+        Ok((rest, Self { labels, stacks }))
     }
+
+    fn stack_index(&self, label: usize) -> Result<usize, MoveError> {
+        let label_str = label.to_string();
+        self.labels
+            .iter()
+            .position(|x| *x == label_str)
+            .ok_or(MoveError::UnknownStack(label))
+    }
+
+    pub fn apply<C: CraneModel>(&mut self, crane: &C, m: &GameMove) -> Result<(), MoveError> {
+        let from = self.stack_index(m.from)?;
+        let to = self.stack_index(m.to)?;
+        let work = &mut self.stacks[from];
+        let Some(split) = work.len().checked_sub(m.quantity) else {
+            return Err(MoveError::NotEnoughCrates(*m));
+        };
+        let mut lifted = work.split_off(split);
+        crane.set_down(&mut lifted);
+        self.stacks[to].append(&mut lifted);
+        Ok(())
+    }
+
+    pub fn apply_all<C: CraneModel>(
+        &mut self,
+        crane: &C,
+        moves: &[GameMove],
+    ) -> Result<(), MoveError> {
+        moves.iter().try_for_each(|m| self.apply(crane, m))
+    }
+
     fn get_tops(&self) -> String {
-        self.board
+        self.stacks
             .iter()
-            .map(|x| x.last().unwrap().label.clone())
+            .filter_map(|x| x.last())
             .fold(String::new(), |acc, x| acc + x)
     }
 }
 
-impl<'a> Parser<&'a str, Self, Error<&'a str>> for GameBoard<'a> {
-    fn parse(&mut self, input: &'a str) -> nom::IResult<&'a str, Self, Error<&'a str>> {
-        let (input, crates) = separated_list1(
-            newline,
-            separated_list1(
-                tag(" "),
-                alt((tag("   ").map(|_| None), Crate::default().map(Some))),
-            ),
-        )(input)?;
-        let (input, _) = newline(input)?;
-        let (input, labels) = separated_list1(
-            tag(" "),
-            delimited(tag(" "), complete::u8.map(|x| x.to_string()), tag(" ")),
-        )(input)?;
-        let (input, _) = newline(input)?;
-        //self._labels = labels;
-        let mut board = vec![Vec::new(); crates[0].len()];
-        for cols in crates {
-            for (col, c) in cols.iter().enumerate() {
-                if c.is_none() {
-                    continue;
-                }
-                board[col].push(c.unwrap());
-            }
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self
+            .labels
+            .iter()
+            .zip(&self.stacks)
+            .map(|(label, stack)| {
+                stack
+                    .iter()
+                    .map(|x| x.len() + 2)
+                    .chain([label.len(), 3])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or_default();
+        for row in (0..height).rev() {
+            let line = self
+                .stacks
+                .iter()
+                .zip(&widths)
+                .map(|(stack, width)| {
+                    let cell = stack.get(row).map(|x| format!("[{x}]")).unwrap_or_default();
+                    format!("{cell:^width$}")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(f, "{line}")?;
         }
-        board.iter_mut().for_each(|col| col.reverse());
-        self.board = board;
-        let b = GameBoard {
-            _labels: labels,
-            board: self.board.clone(),
-        };
-        Ok((input, b))
+        let line = self
+            .labels
+            .iter()
+            .zip(&widths)
+            .map(|(label, width)| format!("{label:^width$}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        write!(f, "{line}")
+    }
+}
+
+/// The moves made on some stacks, drawn with the stacks before and after
+/// each of them by replaying the moves.
+struct MoveLog<'a, C> {
+    crane: &'a C,
+    start: Stacks,
+    moves: Vec<GameMove>,
+}
+
+impl<'a, C: CraneModel> MoveLog<'a, C> {
+    /// Checks every move can be made so the log can always be replayed.
+    pub fn new(crane: &'a C, start: Stacks, moves: Vec<GameMove>) -> Result<Self, MoveError> {
+        start.clone().apply_all(crane, &moves)?;
+        Ok(Self {
+            crane,
+            start,
+            moves,
+        })
     }
 }
 
-fn parse_input(input: &str) -> nom::IResult<&str, (GameBoard, Vec<GameMove>)> {
+impl<C: CraneModel> Display for MoveLog<'_, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut current = self.start.clone();
+        writeln!(f, "{current}")?;
+        for m in &self.moves {
+            current.apply(self.crane, m).map_err(|_| std::fmt::Error)?;
+            writeln!(f)?;
+            writeln!(f, "{m}")?;
+            writeln!(f, "{current}")?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(input: &str) -> nom::IResult<&str, (Stacks, Vec<GameMove>)> {
     separated_pair(
-        GameBoard::default(),
+        Stacks::parse,
         newline,
         separated_list1(newline, GameMove::default()),
     )(input)
 }
 
-fn part1(input: &str) -> String {
+fn run<C: CraneModel>(crane: &C, input: &str) -> Stacks {
     let (_, (mut board, moves)) = parse_input(input).unwrap();
-    for m in moves {
-        board.game1_move(&m);
-    }
-    board.get_tops()
+    board.apply_all(crane, &moves).unwrap();
+    board
+}
+
+fn show<'a, C: CraneModel>(crane: &'a C, input: &str) -> MoveLog<'a, C> {
+    let (_, (board, moves)) = parse_input(input).unwrap();
+    MoveLog::new(crane, board, moves).unwrap()
+}
+
+fn part1(input: &str) -> String {
+    run(&CrateMover9000, input).get_tops()
 }
 
 fn part2(input: &str) -> String {
-    let (_, (mut board, moves)) = parse_input(input).unwrap();
-    for m in moves {
-        board.game2_move(&m);
-    }
-    board.get_tops()
+    run(&CrateMover9001, input).get_tops()
 }
 
 fn main() {
     //Read in file
     let file = fs::read_to_string("input").unwrap();
 
-    //read in the parts of the file
+    if std::env::args().any(|arg| arg == "--show") {
+        println!("{}", show(&CrateMover9000, &file));
+        println!("{}", show(&CrateMover9001, &file));
+    }
 
     println!("Part 1: {}", part1(&file));
     println!("Part 2: {}", part2(&file));
@@ -174,4 +299,72 @@ move 1 from 1 to 2";
     fn part2_works() {
         assert_eq!(part2(INPUT), "MCD");
     }
+
+    #[test]
+    fn log_renders_each_step() {
+        let log = show(&CrateMover9000, INPUT).to_string();
+        let steps = log.trim_end().split("\n\n").collect::<Vec<_>>();
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0], INPUT.split("\n\n").next().unwrap());
+        assert_eq!(
+            steps[2],
+            "move 3 from 1 to 3
+        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3 "
+                .lines()
+                .enumerate()
+                .map(|(i, line)| if i == 0 {
+                    line.to_string()
+                } else {
+                    format!("{line:<11}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+
+    #[test]
+    fn wide_labels_and_many_stacks() {
+        let input = "[AB]                                        [Q]
+[CD] [E] [F] [G] [H] [I] [J] [K] [L] [M] [N] [O]
+ 1    2   3   4   5   6   7   8   9   10  11  12
+
+move 2 from 1 to 12
+move 1 from 12 to 10";
+        let (_, (stacks, _)) = parse_input(input).unwrap();
+        assert_eq!(stacks.labels.len(), 12);
+        assert_eq!(stacks.stacks[0], ["CD", "AB"]);
+        assert_eq!(stacks.stacks[11], ["O", "Q"]);
+        assert_eq!(part1(input), "EFGHIJKLCDNAB");
+        assert_eq!(part2(input), "EFGHIJKLABNCD");
+        // what gets drawn parses back to the same stacks
+        let (_, redrawn) = Stacks::parse(&format!("{stacks}\n\n")).unwrap();
+        assert_eq!(redrawn, stacks);
+    }
+
+    #[test]
+    fn bad_moves_are_errors() {
+        let (_, (stacks, _)) = parse_input(INPUT).unwrap();
+        let m = GameMove {
+            quantity: 4,
+            from: 1,
+            to: 2,
+        };
+        assert_eq!(
+            stacks.clone().apply_all(&CrateMover9001, &[m]).unwrap_err(),
+            MoveError::NotEnoughCrates(m)
+        );
+        let m = GameMove {
+            quantity: 1,
+            from: 4,
+            to: 2,
+        };
+        assert!(matches!(
+            MoveLog::new(&CrateMover9001, stacks, vec![m]),
+            Err(MoveError::UnknownStack(4))
+        ));
+    }
 }
@@ -1,5 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_1::model::parse;
 use day_1::part1::part1;
 use day_1::part2::part2;
 
@@ -7,7 +10,10 @@ use error_stack::{Result, ResultExt};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
 enum Day1Error {
+    #[error("Parsing failed")]
+    ParseError,
     #[error("Part 1 failed")]
     Part1Error,
     #[error("Part 2 failed")]
@@ -16,9 +22,14 @@ enum Day1Error {
 
 fn main() -> Result<(), Day1Error> {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input).change_context(Day1Error::Part1Error)?;
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input).change_context(Day1Error::Part2Error)?;
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let lines = parse(input).change_context(Day1Error::ParseError)?;
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&lines).change_context(Day1Error::Part1Error)?;
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&lines).change_context(Day1Error::Part2Error)?;
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
     Ok(())
 }
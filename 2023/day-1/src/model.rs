@@ -0,0 +1,30 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    self,
+    character::complete::{alphanumeric1, newline},
+    multi::separated_list1,
+};
+
+use error_stack::{Report, Result, ResultExt};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day1ModelError {
+    #[error("Problem parsing Day 1")]
+    ParseError,
+}
+
+/// Day-1 calibration document of AC2023, split into its lines
+///
+/// # Arguments
+/// - input the input for day1 as a string
+///
+/// # Errors
+/// errors when can't parse the input
+pub fn parse(input: &str) -> Result<Vec<&str>, Day1ModelError> {
+    let (_input, lines) = separated_list1(newline, alphanumeric1)(input)
+        .map_err(|x: nom::Err<nom::error::Error<&str>>| Report::from(x.to_owned()))
+        .change_context(Day1ModelError::ParseError)?;
+    Ok(lines)
+}
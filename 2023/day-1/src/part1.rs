@@ -1,19 +1,12 @@
 #![warn(clippy::all, clippy::pedantic)]
 
 use log::trace;
-use nom::{
-    self,
-    character::complete::{alphanumeric1, newline},
-    multi::separated_list1,
-};
 
-use error_stack::{Report, Result, ResultExt};
+use error_stack::Result;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum Day1Part1Error {
-    #[error("Problem parsing Day 1")]
-    ParseError,
     #[error("Day 1 Input parsed to Empty")]
     EmptyInput,
 }
@@ -21,14 +14,19 @@ pub enum Day1Part1Error {
 /// Day-1 part 1 of AC2023
 ///
 /// # Arguments
-/// - input the input for day1 as a string
+/// - lines the lines of the calibration document
 ///
 /// # Errors
-/// errors when can't parse the input
-pub fn part1(input: &str) -> Result<String, Day1Part1Error> {
-    let (_input, values) = parse_input(input)
-        .map_err(|x| Report::from(x.to_owned()))
-        .change_context(Day1Part1Error::ParseError)?;
+/// errors when a line has no digits in it
+pub fn part1(lines: &[&str]) -> Result<String, Day1Part1Error> {
+    let values = lines
+        .iter()
+        .map(|v| {
+            v.chars()
+                .filter_map(|x| x.to_digit(10))
+                .collect::<Vec<u32>>()
+        })
+        .collect::<Vec<Vec<u32>>>();
     trace!("{values:?}");
     values
         .iter()
@@ -41,22 +39,10 @@ pub fn part1(input: &str) -> Result<String, Day1Part1Error> {
         .map(|x| x.to_string())
 }
 
-fn parse_input(input: &str) -> nom::IResult<&str, Vec<Vec<u32>>> {
-    let (i, j) = separated_list1(newline, alphanumeric1)(input)?;
-    let res = j
-        .iter()
-        .map(|v| {
-            v.chars()
-                .filter_map(|x| x.to_digit(10))
-                .collect::<Vec<u32>>()
-        })
-        .collect::<Vec<Vec<u32>>>();
-    Ok((i, res))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
 
     const INPUT: &str = "1abc2
 pqr3stu8vwx
@@ -66,7 +52,7 @@ treb7uchet";
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part1_works() {
-        let result = part1(INPUT).unwrap();
+        let result = part1(&parse(INPUT).unwrap()).unwrap();
         assert_eq!(result, "142".to_string());
     }
 }
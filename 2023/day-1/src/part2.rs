@@ -17,14 +17,14 @@ pub enum Day1Part2Error {
 /// Day 1 Part 2 of AOC2023
 ///
 /// # Arguments
-/// - puzzle input
+/// - lines the lines of the calibration document
 ///
 /// # Errors
-/// this panics if there is no numbers in a line
-pub fn part2(input: &str) -> Result<String, Day1Part2Error> {
-    let values = input
-        .lines()
-        .map(parse_line)
+/// this errors if there is no numbers in a line
+pub fn part2(lines: &[&str]) -> Result<String, Day1Part2Error> {
+    let values = lines
+        .iter()
+        .map(|line| parse_line(line))
         .collect::<Result<Vec<Vec<u32>>, _>>()?;
     trace!("{values:?}");
     values
@@ -79,6 +79,7 @@ fn parse_line(line: &str) -> Result<Vec<u32>, Day1Part2Error> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
 
     const INPUT: &str = "two1nine
 eightwothree
@@ -91,7 +92,7 @@ zoneight234
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part2_works() {
-        let result = part2(INPUT).unwrap();
+        let result = part2(&parse(INPUT).unwrap()).unwrap();
         assert_eq!(result, "281".to_string());
     }
 }
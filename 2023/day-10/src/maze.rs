@@ -0,0 +1,459 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::fmt::Display;
+
+use glam::IVec2;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::{eof, value},
+    multi::{many1, separated_list1},
+    sequence::terminated,
+    IResult,
+};
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    #[must_use]
+    pub fn step(self) -> IVec2 {
+        match self {
+            Direction::Up => IVec2::NEG_Y,
+            Direction::Down => IVec2::Y,
+            Direction::Left => IVec2::NEG_X,
+            Direction::Right => IVec2::X,
+        }
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum PipeType {
+    // 'S'
+    Start,
+    // '-'
+    Horizontal,
+    // '|'
+    Vertical,
+    // 'F'
+    DownRight,
+    // '7'
+    DownLeft,
+    // 'L'
+    UpRight,
+    // 'J'
+    UpLeft,
+    // '.'
+    None,
+}
+
+impl PipeType {
+    /// The directions this pipe leads out in, the start leads everywhere
+    /// until we know what is under it.
+    #[must_use]
+    pub fn connections(self) -> &'static [Direction] {
+        use Direction::{Down, Left, Right, Up};
+        match self {
+            PipeType::Start => &[Up, Down, Left, Right],
+            PipeType::Horizontal => &[Left, Right],
+            PipeType::Vertical => &[Up, Down],
+            PipeType::DownRight => &[Down, Right],
+            PipeType::DownLeft => &[Down, Left],
+            PipeType::UpRight => &[Up, Right],
+            PipeType::UpLeft => &[Up, Left],
+            PipeType::None => &[],
+        }
+    }
+
+    /// The pipe that joins up `a` and `b`.
+    #[must_use]
+    pub fn joining(a: Direction, b: Direction) -> Option<Self> {
+        use Direction::{Down, Left, Right, Up};
+        match (a, b) {
+            (Left, Right) | (Right, Left) => Some(PipeType::Horizontal),
+            (Up, Down) | (Down, Up) => Some(PipeType::Vertical),
+            (Down, Right) | (Right, Down) => Some(PipeType::DownRight),
+            (Down, Left) | (Left, Down) => Some(PipeType::DownLeft),
+            (Up, Right) | (Right, Up) => Some(PipeType::UpRight),
+            (Up, Left) | (Left, Up) => Some(PipeType::UpLeft),
+            _ => None,
+        }
+    }
+
+    /// Where a pipe entered going `heading` sends you next.
+    fn exit(self, heading: Direction) -> Option<Direction> {
+        let back = heading.opposite();
+        let connections = self.connections();
+        if connections.len() != 2 || !connections.contains(&back) {
+            return None;
+        }
+        connections.iter().copied().find(|x| *x != back)
+    }
+
+    fn box_drawing(self, heavy: bool) -> char {
+        match (self, heavy) {
+            (PipeType::Start, _) => 'S',
+            (PipeType::Horizontal, false) => '─',
+            (PipeType::Vertical, false) => '│',
+            (PipeType::DownRight, false) => '┌',
+            (PipeType::DownLeft, false) => '┐',
+            (PipeType::UpRight, false) => '└',
+            (PipeType::UpLeft, false) => '┘',
+            (PipeType::Horizontal, true) => '━',
+            (PipeType::Vertical, true) => '┃',
+            (PipeType::DownRight, true) => '┏',
+            (PipeType::DownLeft, true) => '┓',
+            (PipeType::UpRight, true) => '┗',
+            (PipeType::UpLeft, true) => '┛',
+            (PipeType::None, _) => ' ',
+        }
+    }
+}
+
+impl Display for PipeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Start => "S",
+                Self::Horizontal => "-",
+                Self::Vertical => "|",
+                Self::DownRight => "F",
+                Self::DownLeft => "7",
+                Self::UpRight => "L",
+                Self::UpLeft => "J",
+                Self::None => ".",
+            }
+        )
+    }
+}
+
+/// Where a tile sits relative to the main loop.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TileClass {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// The pipe maze with the start tile swapped for the pipe under it.
+#[derive(Debug, Clone)]
+pub struct PipeMaze {
+    tiles: Vec<PipeType>,
+    width: usize,
+    height: usize,
+    start: IVec2,
+    start_type: PipeType,
+    main_loop: Vec<IVec2>,
+}
+
+impl PipeMaze {
+    /// Builds the maze from the puzzle input, `None` when the rows are
+    /// different lengths, there isn't exactly one start, or the start isn't
+    /// on a loop.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let (_, rows) = parse_input(input).ok()?;
+        let width = rows[0].len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        let tiles = rows.into_iter().flatten().collect::<Vec<_>>();
+        let mut starts = tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x == PipeType::Start)
+            .map(|(i, _)| i);
+        let start = starts.next()?;
+        if starts.next().is_some() {
+            return None;
+        }
+        let start = IVec2::new(
+            i32::try_from(start % width).ok()?,
+            i32::try_from(start / width).ok()?,
+        );
+        let mut maze = Self {
+            tiles,
+            width,
+            height,
+            start,
+            start_type: PipeType::Start,
+            main_loop: Vec::new(),
+        };
+        (maze.start_type, maze.main_loop) = maze.infer_start()?;
+        Some(maze)
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn start(&self) -> IVec2 {
+        self.start
+    }
+
+    /// The pipe hidden under `S`.
+    #[must_use]
+    pub fn start_type(&self) -> PipeType {
+        self.start_type
+    }
+
+    fn index(&self, pos: IVec2) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// The pipe at `pos`, with the start already resolved.
+    #[must_use]
+    pub fn pipe(&self, pos: IVec2) -> PipeType {
+        match self.index(pos).map(|i| self.tiles[i]) {
+            Some(PipeType::Start) => self.start_type,
+            Some(pipe) => pipe,
+            None => PipeType::None,
+        }
+    }
+
+    /// Follows the pipes leaving the start going `heading`, giving back the
+    /// tiles visited if it makes it back round to the start along with the
+    /// direction it came back in from.
+    fn follow(&self, mut heading: Direction) -> Option<(Vec<IVec2>, Direction)> {
+        let mut path = vec![self.start];
+        let mut pos = self.start + heading.step();
+        while pos != self.start {
+            let i = self.index(pos)?;
+            heading = self.tiles[i].exit(heading)?;
+            path.push(pos);
+            pos += heading.step();
+        }
+        Some((path, heading.opposite()))
+    }
+
+    /// Tries each way out of the start until one comes back round, the two
+    /// ends of that loop tell us what the start has to be.
+    fn infer_start(&self) -> Option<(PipeType, Vec<IVec2>)> {
+        PipeType::Start.connections().iter().find_map(|heading| {
+            let (path, back) = self.follow(*heading)?;
+            Some((PipeType::joining(*heading, back)?, path))
+        })
+    }
+
+    /// The main loop in order, starting at the start, as the corners and
+    /// edges of a closed polygon.
+    #[must_use]
+    pub fn main_loop(&self) -> &[IVec2] {
+        &self.main_loop
+    }
+
+    /// How many steps along the loop the point farthest from the start is.
+    #[must_use]
+    pub fn farthest(&self) -> usize {
+        self.main_loop.len() / 2
+    }
+
+    /// Classifies every tile, indexed `[y][x]`.
+    ///
+    /// Scanning each row left to right, every loop tile with a pipe going up
+    /// crosses the boundary so flips whether we are inside or not.
+    #[must_use]
+    pub fn classify(&self) -> Vec<Vec<TileClass>> {
+        let mut on_loop = vec![false; self.tiles.len()];
+        for i in self.main_loop.iter().filter_map(|pos| self.index(*pos)) {
+            on_loop[i] = true;
+        }
+        (0..self.height)
+            .map(|y| {
+                let mut inside = false;
+                (y * self.width..(y + 1) * self.width)
+                    .map(|i| {
+                        if !on_loop[i] {
+                            return if inside {
+                                TileClass::Inside
+                            } else {
+                                TileClass::Outside
+                            };
+                        }
+                        let pipe = match self.tiles[i] {
+                            PipeType::Start => self.start_type,
+                            pipe => pipe,
+                        };
+                        if pipe.connections().contains(&Direction::Up) {
+                            inside = !inside;
+                        }
+                        TileClass::Loop
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// How many tiles the loop encloses.
+    #[must_use]
+    pub fn inside_count(&self) -> usize {
+        self.classify()
+            .iter()
+            .flatten()
+            .filter(|x| **x == TileClass::Inside)
+            .count()
+    }
+}
+
+/// Draws the loop with heavy box-drawing lines, any other pipes with light
+/// ones, and shades in the tiles inside the loop.
+impl Display for PipeMaze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.classify().iter().enumerate() {
+            let line = row
+                .iter()
+                .zip(&self.tiles[y * self.width..(y + 1) * self.width])
+                .map(|(class, pipe)| match class {
+                    TileClass::Loop if *pipe == PipeType::Start => {
+                        self.start_type.box_drawing(true)
+                    }
+                    TileClass::Loop => pipe.box_drawing(true),
+                    TileClass::Inside => '▒',
+                    TileClass::Outside => pipe.box_drawing(false),
+                })
+                .collect::<String>();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_pipe(input: &str) -> IResult<&str, PipeType> {
+    alt((
+        value(PipeType::Start, tag("S")),
+        value(PipeType::Horizontal, tag("-")),
+        value(PipeType::Vertical, tag("|")),
+        value(PipeType::DownRight, tag("F")),
+        value(PipeType::DownLeft, tag("7")),
+        value(PipeType::UpRight, tag("L")),
+        value(PipeType::UpLeft, tag("J")),
+        value(PipeType::None, tag(".")),
+    ))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<PipeType>>> {
+    terminated(
+        separated_list1(complete::line_ending, many1(parse_pipe)),
+        alt((complete::line_ending, eof)),
+    )(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    #[test]
+    fn start_is_inferred() {
+        let maze = PipeMaze::parse(INPUT).unwrap();
+        assert_eq!(maze.start(), IVec2::new(1, 1));
+        assert_eq!(maze.start_type(), PipeType::DownRight);
+        let maze = PipeMaze::parse(
+            "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ",
+        )
+        .unwrap();
+        assert_eq!(maze.start_type(), PipeType::DownRight);
+        assert_eq!(maze.farthest(), 8);
+    }
+
+    #[test]
+    fn no_start_loop_is_none() {
+        assert!(PipeMaze::parse("...\n.S.\n...").is_none());
+        assert!(PipeMaze::parse("S-7\n|.|\nL-J\n.S.").is_none());
+        assert!(PipeMaze::parse("S-7\n|.|\nL-J.").is_none());
+    }
+
+    #[test]
+    fn loop_is_a_closed_polygon() {
+        let maze = PipeMaze::parse(INPUT).unwrap();
+        let main_loop = maze.main_loop();
+        assert_eq!(main_loop.first(), Some(&maze.start()));
+        assert!(main_loop
+            .iter()
+            .zip(main_loop.iter().cycle().skip(1))
+            .all(|(a, b)| (*a - *b).abs().dot(IVec2::ONE) == 1));
+        // pick's theorem says the area of the polygon gives the tiles inside
+        let twice_area = main_loop
+            .iter()
+            .zip(main_loop.iter().cycle().skip(1))
+            .map(|(a, b)| a.x * b.y - a.y * b.x)
+            .sum::<i32>()
+            .unsigned_abs() as usize;
+        assert_eq!(
+            twice_area / 2 + 1 - main_loop.len() / 2,
+            maze.inside_count()
+        );
+    }
+
+    #[test]
+    fn classifies_every_tile() {
+        let maze = PipeMaze::parse(INPUT).unwrap();
+        let classes = maze.classify();
+        assert_eq!(classes.len(), maze.height());
+        assert!(classes.iter().all(|row| row.len() == maze.width()));
+        assert_eq!(classes[0][0], TileClass::Outside);
+        assert_eq!(classes[1][1], TileClass::Loop);
+        assert_eq!(classes[6][2], TileClass::Inside);
+        assert_eq!(classes[3][4], TileClass::Outside);
+        assert_eq!(maze.inside_count(), 4);
+    }
+
+    #[test]
+    fn renders_with_box_drawing() {
+        let maze = PipeMaze::parse(INPUT).unwrap();
+        assert_eq!(
+            maze.to_string(),
+            "
+ ┏━━━━━━┓
+ ┃┏━━━━┓┃
+ ┃┃    ┃┃
+ ┃┃    ┃┃
+ ┃┗━┓┏━┛┃
+ ┃▒▒┃┃▒▒┃
+ ┗━━┛┗━━┛
+
+"
+        );
+    }
+}
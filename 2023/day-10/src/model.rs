@@ -0,0 +1,15 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use crate::maze::PipeMaze;
+
+/// day 10 pipe maze of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when it cannot parse the input OR when the start is not on a loop
+#[must_use]
+pub fn parse(input: &str) -> PipeMaze {
+    PipeMaze::parse(input).expect("aoc always parse")
+}
@@ -1,12 +1,20 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_11::parse;
 use day_11::part1;
 use day_11::part2;
 
 fn main() {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input, 1_000_000);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let universe = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&universe);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&universe, 1_000_000);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
@@ -0,0 +1,12 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use crate::universe::Universe;
+
+/// day 11 image of the universe of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+#[must_use]
+pub fn parse(input: &str) -> Universe {
+    Universe::parse(input)
+}
@@ -0,0 +1,138 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! The galaxies in the image and how far apart they are once the empty rows
+//! and columns have grown.
+//!
+//! Manhattan distance splits into one sum per axis, and along an axis the
+//! sorted, expanded coordinates give every pair's distance from a running
+//! total, so nothing is ever compared pair by pair.
+
+/// Where the galaxies are in the image as it was taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Universe {
+    pub galaxies: Vec<(u64, u64)>,
+}
+
+impl Universe {
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        let galaxies = input
+            .lines()
+            .zip(0..)
+            .flat_map(|(line, y)| {
+                line.chars()
+                    .zip(0..)
+                    .filter_map(move |(c, x)| (c != '.').then_some((x, y)))
+            })
+            .collect();
+        Self { galaxies }
+    }
+
+    /// The galaxies once every empty row and column is `expansion` times as
+    /// wide, in the same order as [`Self::galaxies`].
+    #[must_use]
+    pub fn expanded(&self, expansion: u64) -> Vec<(u64, u64)> {
+        let xs = expand_axis(self.galaxies.iter().map(|g| g.0), expansion);
+        let ys = expand_axis(self.galaxies.iter().map(|g| g.1), expansion);
+        self.galaxies
+            .iter()
+            .map(|(x, y)| (lookup(&xs, *x), lookup(&ys, *y)))
+            .collect()
+    }
+
+    /// The sum of the shortest paths between every pair of galaxies, each
+    /// pair counted once, after expanding by `expansion`.
+    #[must_use]
+    pub fn distance_total(&self, expansion: u64) -> u64 {
+        let xs = expand_axis(self.galaxies.iter().map(|g| g.0), expansion);
+        let ys = expand_axis(self.galaxies.iter().map(|g| g.1), expansion);
+        axis_total(&xs) + axis_total(&ys)
+    }
+}
+
+/// Sorts one axis's coordinates and pairs each with where it ends up, every
+/// empty line between two neighbours counting `expansion` times.
+fn expand_axis(coords: impl Iterator<Item = u64>, expansion: u64) -> Vec<(u64, u64)> {
+    let mut coords = coords.collect::<Vec<_>>();
+    coords.sort_unstable();
+    let mut expanded: Vec<(u64, u64)> = Vec::with_capacity(coords.len());
+    for coord in coords {
+        let moved = match expanded.last() {
+            None => coord * expansion,
+            Some(&(before, moved)) if before == coord => moved,
+            Some(&(before, moved)) => moved + 1 + (coord - before - 1) * expansion,
+        };
+        expanded.push((coord, moved));
+    }
+    expanded
+}
+
+fn lookup(axis: &[(u64, u64)], coord: u64) -> u64 {
+    let index = axis.partition_point(|(c, _)| *c < coord);
+    axis[index].1
+}
+
+/// the distance between every pair along an already sorted axis
+fn axis_total(axis: &[(u64, u64)]) -> u64 {
+    let mut before = 0;
+    axis.iter()
+        .zip(0..)
+        .map(|(&(_, coord), count)| {
+            let total = coord * count - before;
+            before += coord;
+            total
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    /// every pair, the long way round
+    fn pairwise(galaxies: &[(u64, u64)]) -> u64 {
+        galaxies
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| galaxies[i + 1..].iter().map(move |b| (a, b)))
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum()
+    }
+
+    #[test]
+    fn expands_like_the_puzzle() {
+        let universe = Universe::parse(INPUT);
+        let expanded = universe.expanded(2);
+        assert_eq!(expanded[0], (4, 0));
+        assert_eq!(expanded[7], (0, 11));
+        assert_eq!(expanded[4], (1, 6));
+    }
+
+    #[test]
+    fn matches_every_pair() {
+        let universe = Universe::parse(INPUT);
+        for (expansion, expected) in [(1, 292), (2, 374), (10, 1030), (100, 8410)] {
+            assert_eq!(universe.distance_total(expansion), expected);
+            assert_eq!(pairwise(&universe.expanded(expansion)), expected);
+        }
+        let shuffled = Universe {
+            galaxies: vec![(9, 3), (0, 0), (9, 3), (4, 12), (2, 7)],
+        };
+        for expansion in 1..6 {
+            assert_eq!(
+                shuffled.distance_total(expansion),
+                pairwise(&shuffled.expanded(expansion))
+            );
+        }
+    }
+}
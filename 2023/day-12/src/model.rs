@@ -0,0 +1,81 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult, Parser,
+};
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub enum SpringStatus {
+    Working,
+    Failing,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub springs: Vec<SpringStatus>,
+    pub broken_spans: Vec<u32>,
+}
+
+impl Row {
+    /// the row as it really is, `copies` of it joined by unknown springs
+    #[must_use]
+    pub fn unfolded(&self, copies: usize) -> Self {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * copies);
+        for copy in 0..copies {
+            if copy > 0 {
+                springs.push(SpringStatus::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Self {
+            springs,
+            broken_spans: self.broken_spans.repeat(copies),
+        }
+    }
+}
+
+fn parse_spa_spots(input: &str) -> IResult<&str, Vec<SpringStatus>> {
+    many1(alt((
+        tag(".").map(|_| SpringStatus::Working),
+        tag("#").map(|_| SpringStatus::Failing),
+        tag("?").map(|_| SpringStatus::Unknown),
+    )))(input)
+}
+
+fn parse_spa_spans(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(tag(","), complete::u32)(input)
+}
+
+pub(crate) fn parse_spa_rows(input: &str) -> IResult<&str, Row> {
+    separated_pair(parse_spa_spots, complete::space1, parse_spa_spans)(input).map(
+        |(input, (springs, broken_spans))| {
+            (
+                input,
+                Row {
+                    springs,
+                    broken_spans,
+                },
+            )
+        },
+    )
+}
+
+/// day 12 condition records of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a line isn't springs of `.`, `#` and `?` then their comma separated group sizes
+#[must_use]
+pub fn parse(input: &str) -> Vec<Row> {
+    let (_, spas) = separated_list1(complete::line_ending, parse_spa_rows)(input)
+        .expect("AOC always has valid input");
+    spas
+}
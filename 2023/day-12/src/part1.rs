@@ -1,28 +1,9 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    multi::{many1, separated_list1},
-    sequence::separated_pair,
-    IResult, Parser,
-};
-
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
-enum SpringStatus {
-    Working,
-    Failing,
-    Unknown,
-}
-
-struct Row {
-    springs: Vec<SpringStatus>,
-    broken_spans: Vec<u32>,
-}
+use crate::model::{Row, SpringStatus};
 
 impl Row {
-    fn process(&self) -> usize {
+    fn count_by_brute_force(&self) -> usize {
         let num_broken = self.broken_spans.iter().sum();
         let row_len = self.springs.len();
         let max_perm = 1_u32 << row_len;
@@ -43,7 +24,7 @@ impl Row {
                 self.springs
                     .iter()
                     .zip(x.iter())
-                    .all(|(a, b)| (a == b || *a == SpringStatus::Unknown))
+                    .all(|(a, b)| a == b || *a == SpringStatus::Unknown)
             })
             .filter(|x| {
                 let (mut array, last, current_run) = x.iter().fold(
@@ -77,53 +58,19 @@ impl Row {
 /// day 12 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
-///
-/// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// - spas the parsed condition records
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (_, spas) = parse_input(input).expect("AOC always has valid input");
+pub fn part1(spas: &[Row]) -> String {
     spas.iter()
-        .map(|x| x.process() as u64)
+        .map(|x| x.count_by_brute_force() as u64)
         .sum::<u64>()
         .to_string()
 }
 
-fn parse_spa_spots(input: &str) -> IResult<&str, Vec<SpringStatus>> {
-    many1(alt((
-        tag(".").map(|_| SpringStatus::Working),
-        tag("#").map(|_| SpringStatus::Failing),
-        tag("?").map(|_| SpringStatus::Unknown),
-    )))(input)
-}
-
-fn parse_spa_spans(input: &str) -> IResult<&str, Vec<u32>> {
-    separated_list1(tag(","), complete::u32)(input)
-}
-
-fn parse_spa_rows(input: &str) -> IResult<&str, Row> {
-    separated_pair(parse_spa_spots, complete::space1, parse_spa_spans)(input).map(
-        |(input, (springs, broken_spans))| {
-            (
-                input,
-                Row {
-                    springs,
-                    broken_spans,
-                },
-            )
-        },
-    )
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Row>> {
-    separated_list1(complete::line_ending, parse_spa_rows)(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::{parse, parse_spa_rows};
     use rstest::rstest;
 
     #[rstest]
@@ -135,7 +82,7 @@ mod test {
     #[case("?###???????? 3,2,1", 10)]
     fn line_test(#[case] input: &str, #[case] expected: usize) {
         let (_, row) = parse_spa_rows(input).expect("should parse");
-        assert_eq!(row.process(), expected);
+        assert_eq!(row.count_by_brute_force(), expected);
     }
 
     const INPUT: &str = "???.### 1,1,3
@@ -147,7 +94,7 @@ mod test {
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT);
+        let result = part1(&parse(INPUT));
         assert_eq!(result, "21".to_string());
     }
 }
@@ -1,23 +1,11 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use std::{collections::HashMap, iter::repeat};
-
-use itertools::Itertools;
-use nom::{
-    bytes::complete::{is_a, tag},
-    character::complete,
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
-};
-
-struct Row {
-    springs: String,
-    broken_spans: Vec<u32>,
-}
+use std::collections::HashMap;
+
+use crate::model::{Row, SpringStatus};
 
 impl Row {
-    fn process(&self) -> usize {
+    fn count_arrangements(&self) -> usize {
         let mut cache = HashMap::new();
         self.dynamic_search(&mut cache, (0, 0, 0))
     }
@@ -43,8 +31,8 @@ impl Row {
             }
             return 0;
         }
-        match self.springs.as_bytes()[data_index] {
-            b'.' => {
+        match self.springs[data_index] {
+            SpringStatus::Working => {
                 //previous was also working just go to next data point
                 if group_size == 0 {
                     return self.dynamic_search(cache, (data_index + 1, group_index, 0));
@@ -60,7 +48,7 @@ impl Row {
                 //completed a group keep going
                 self.dynamic_search(cache, (data_index + 1, group_index + 1, 0))
             }
-            b'#' => {
+            SpringStatus::Failing => {
                 //too many for our group
                 if group_index >= self.broken_spans.len()
                     || group_size + 1 > self.broken_spans[group_index]
@@ -71,7 +59,7 @@ impl Row {
                 //haven't completed group yet keep looking
                 self.dynamic_search(cache, (data_index + 1, group_index, group_size + 1))
             }
-            b'?' => {
+            SpringStatus::Unknown => {
                 if let Some(res) = cache.get(&(data_index, group_index, group_size)).copied() {
                     return res;
                 }
@@ -101,7 +89,6 @@ impl Row {
                 cache.insert((data_index, group_index, group_size), perms);
                 perms
             }
-            _ => unreachable!(),
         }
     }
 }
@@ -109,53 +96,19 @@ impl Row {
 /// day 12 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
-///
-/// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// - spas the parsed condition records
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let (_, spas) = parse_input(input).expect("AOC always has valid input");
+pub fn part2(spas: &[Row]) -> String {
     spas.iter()
-        .map(|x| x.process() as u64)
+        .map(|x| x.unfolded(5).count_arrangements() as u64)
         .sum::<u64>()
         .to_string()
 }
 
-fn parse_spa_spots(input: &str) -> IResult<&str, &str> {
-    is_a(".#?")(input)
-}
-
-fn parse_spa_spans(input: &str) -> IResult<&str, Vec<u32>> {
-    separated_list1(tag(","), complete::u32)(input)
-}
-
-fn parse_spa_rows(input: &str) -> IResult<&str, Row> {
-    separated_pair(parse_spa_spots, complete::space1, parse_spa_spans)(input).map(
-        |(input, (springs, broken_spans))| {
-            (
-                input,
-                Row {
-                    springs: std::iter::repeat(springs).take(5).join("?"),
-                    broken_spans: repeat(broken_spans.iter())
-                        .take(5)
-                        .flatten()
-                        .copied()
-                        .collect(),
-                },
-            )
-        },
-    )
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Row>> {
-    separated_list1(complete::line_ending, parse_spa_rows)(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::{parse, parse_spa_rows};
     use rstest::rstest;
 
     #[rstest]
@@ -167,7 +120,7 @@ mod test {
     #[case("?###???????? 3,2,1", 506_250)]
     fn line_test(#[case] input: &str, #[case] expected: usize) {
         let (_, row) = parse_spa_rows(input).expect("should parse");
-        assert_eq!(row.process(), expected);
+        assert_eq!(row.unfolded(5).count_arrangements(), expected);
     }
 
     const INPUT: &str = "???.### 1,1,3
@@ -179,7 +132,7 @@ mod test {
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "525152".to_string());
     }
 }
@@ -0,0 +1,169 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! The drawings of ash and rocks as one bitmask per row and per column, so
+//! comparing two lines is an xor and a popcount.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// a line between two rows
+    Horizontal,
+    /// a line between two columns
+    Vertical,
+}
+
+/// A line of reflection, `before` being how many rows or columns are above
+/// or left of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: Axis,
+    pub before: usize,
+    /// how many cells differ from their mirror image
+    pub mismatches: usize,
+}
+
+impl Reflection {
+    /// what the line is worth in the puzzle's summary
+    #[must_use]
+    pub fn score(&self) -> usize {
+        match self.axis {
+            Axis::Horizontal => self.before * 100,
+            Axis::Vertical => self.before,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Drawing {
+    /// bit x of row y set for a rock
+    pub rows: Vec<u64>,
+    /// bit y of column x set for a rock
+    pub cols: Vec<u64>,
+}
+
+impl Drawing {
+    /// Every line that mirrors the drawing with at most `smudges` cells
+    /// needing to change, horizontal lines first.
+    #[must_use]
+    pub fn reflections(&self, smudges: usize) -> Vec<Reflection> {
+        let horizontal = mirror_lines(&self.rows, smudges).map(|(before, mismatches)| Reflection {
+            axis: Axis::Horizontal,
+            before,
+            mismatches,
+        });
+        let vertical = mirror_lines(&self.cols, smudges).map(|(before, mismatches)| Reflection {
+            axis: Axis::Vertical,
+            before,
+            mismatches,
+        });
+        horizontal.chain(vertical).collect()
+    }
+
+    /// the score of the lines needing exactly `smudges` fixed
+    #[must_use]
+    pub fn summarize(&self, smudges: usize) -> usize {
+        self.reflections(smudges)
+            .iter()
+            .filter(|reflection| reflection.mismatches == smudges)
+            .map(Reflection::score)
+            .sum()
+    }
+}
+
+/// The lines between the given masks that mirror them with at most
+/// `smudges` bits differing, with how many do.
+fn mirror_lines(lines: &[u64], smudges: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    (1..lines.len()).filter_map(move |before| {
+        let mut mismatches = 0;
+        for (a, b) in lines[..before].iter().rev().zip(&lines[before..]) {
+            mismatches += (a ^ b).count_ones() as usize;
+            if mismatches > smudges {
+                return None;
+            }
+        }
+        Some((before, mismatches))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::parse_drawing;
+
+    const FIRST: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+
+    const SECOND: &str = "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn encodes_rows_and_columns() {
+        let (_, drawing) = parse_drawing(SECOND).unwrap();
+        assert_eq!(drawing.rows.len(), 7);
+        assert_eq!(drawing.cols.len(), 9);
+        assert_eq!(drawing.rows[0], 0b1_0011_0001);
+        assert_eq!(drawing.cols[0], 0b101_1011);
+    }
+
+    #[test]
+    fn finds_every_line() {
+        let (_, drawing) = parse_drawing(FIRST).unwrap();
+        assert_eq!(
+            drawing.reflections(1),
+            vec![
+                Reflection {
+                    axis: Axis::Horizontal,
+                    before: 3,
+                    mismatches: 1
+                },
+                Reflection {
+                    axis: Axis::Vertical,
+                    before: 5,
+                    mismatches: 0
+                },
+            ]
+        );
+        let (_, drawing) = parse_drawing(SECOND).unwrap();
+        assert_eq!(
+            drawing.reflections(1),
+            vec![
+                Reflection {
+                    axis: Axis::Horizontal,
+                    before: 1,
+                    mismatches: 1
+                },
+                Reflection {
+                    axis: Axis::Horizontal,
+                    before: 4,
+                    mismatches: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn more_smudges_find_more_lines() {
+        let (_, drawing) = parse_drawing(FIRST).unwrap();
+        for smudges in 0..8 {
+            let found = drawing.reflections(smudges);
+            assert!(found.iter().all(|r| r.mismatches <= smudges));
+            assert!(found
+                .iter()
+                .all(|r| drawing.reflections(smudges + 1).contains(r)));
+        }
+        assert_eq!(drawing.reflections(usize::MAX).len(), 6 + 8);
+    }
+
+    #[test]
+    fn rejects_ragged_drawings() {
+        assert!(parse_drawing("#.#\n##").is_err());
+    }
+}
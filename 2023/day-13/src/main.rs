@@ -1,12 +1,20 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_13::parse;
 use day_13::part1;
 use day_13::part2;
 
 fn main() {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let drawings = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&drawings);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&drawings);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
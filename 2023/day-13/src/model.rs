@@ -0,0 +1,59 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    bytes::complete::is_a, character::complete, combinator::verify, multi::separated_list1,
+    sequence::tuple, IResult,
+};
+
+use crate::drawing::Drawing;
+
+/// day 13 drawings of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a drawing has something other than `.` and `#` in it, is ragged or is bigger
+/// than 64 across
+#[must_use]
+pub fn parse(input: &str) -> Vec<Drawing> {
+    let (_, drawings) = parse_input(input).expect("aoc always valid");
+    drawings
+}
+
+pub(crate) fn parse_drawing(input: &str) -> IResult<&str, Drawing> {
+    let (input, lines) = verify(
+        separated_list1(complete::line_ending, is_a(".#")),
+        |lines: &[&str]| {
+            lines.len() <= 64
+                && lines[0].len() <= 64
+                && lines.iter().all(|line| line.len() == lines[0].len())
+        },
+    )(input)?;
+    let width = lines[0].len();
+    let rows = lines
+        .iter()
+        .map(|line| {
+            line.bytes()
+                .enumerate()
+                .filter(|(_, c)| *c == b'#')
+                .fold(0, |mask, (x, _)| mask | 1 << x)
+        })
+        .collect::<Vec<u64>>();
+    let cols = (0..width)
+        .map(|x| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| *row & 1 << x != 0)
+                .fold(0, |mask, (y, _)| mask | 1 << y)
+        })
+        .collect();
+    Ok((input, Drawing { rows, cols }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Drawing>> {
+    separated_list1(
+        tuple((complete::line_ending, complete::line_ending)),
+        parse_drawing,
+    )(input)
+}
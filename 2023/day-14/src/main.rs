@@ -1,12 +1,20 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_14::parse;
 use day_14::part1;
 use day_14::part2;
 
 fn main() {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let platform = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&platform);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&platform);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
@@ -0,0 +1,69 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use glam::IVec2;
+use nom::{bytes::complete::is_a, character::complete, multi::separated_list1, IResult};
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
+pub enum Boulder {
+    Round,
+    Static,
+}
+impl From<char> for Boulder {
+    fn from(value: char) -> Self {
+        match value {
+            'O' => Self::Round,
+            '#' => Self::Static,
+            x => unimplemented!("there is no boulder type for this charachter {x}"),
+        }
+    }
+}
+
+/// The dish with every boulder on it, by column and row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Platform {
+    /// how many columns and rows the dish has
+    pub maxes: IVec2,
+    pub boulders: HashMap<IVec2, Boulder>,
+}
+
+/// day 14 platform of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when the platform has something other than `.`, `O` and `#` in it
+#[must_use]
+pub fn parse(input: &str) -> Platform {
+    let (_, (maxes, boulders)) = parse_input(input).expect("stuff");
+    Platform { maxes, boulders }
+}
+
+fn parse_input(input: &str) -> IResult<&str, (IVec2, HashMap<IVec2, Boulder>)> {
+    let (input, rows) = separated_list1(complete::line_ending, is_a(".O#"))(input)?;
+    let max_rows = i32::try_from(rows.len()).expect("stuff and things");
+    let max_cols = i32::try_from(rows[0].len()).expect("things and stuff?");
+    let maxs = IVec2::from((max_cols, max_rows));
+    let hash = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(line_no, chars)| {
+            chars
+                .chars()
+                .enumerate()
+                .filter_map(move |(col_no, c)| {
+                    (c != '.').then_some((
+                        IVec2::from((
+                            i32::try_from(col_no).expect("hopefully not to small"),
+                            i32::try_from(line_no).expect("this shouldn't be too big"),
+                        )),
+                        c,
+                    ))
+                })
+                .map(|(pos, c)| (pos, Boulder::from(c)))
+        })
+        .collect();
+    Ok((input, (maxs, hash)))
+}
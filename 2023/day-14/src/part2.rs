@@ -3,34 +3,21 @@
 use std::collections::HashMap;
 
 use glam::IVec2;
-use nom::{bytes::complete::is_a, character::complete, multi::separated_list1, IResult};
 
-#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq)]
-enum Boulder {
-    Round,
-    Static,
-}
-impl From<char> for Boulder {
-    fn from(value: char) -> Self {
-        match value {
-            'O' => Self::Round,
-            '#' => Self::Static,
-            x => unimplemented!("there is no boulder type for this charachter {x}"),
-        }
-    }
-}
+use crate::model::{Boulder, Platform};
 
 /// day 14 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - platform the parsed platform
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// panics when the cycle it found doesn't have the grid it needs
 #[must_use]
 #[allow(clippy::cast_sign_loss)]
-pub fn part2(input: &str) -> String {
-    let (_, (maxes, mut map)) = parse_input(input).expect("stuff");
+pub fn part2(platform: &Platform) -> String {
+    let maxes = platform.maxes;
+    let mut map = platform.boulders.clone();
 
     let cycles = 1_000_000_000;
     let mut cur_cycle = 0;
@@ -195,36 +182,10 @@ fn tilt_east(maxes: IVec2, in_map: &HashMap<IVec2, Boulder>) -> HashMap<IVec2, B
 
     out_map
 }
-fn parse_input(input: &str) -> IResult<&str, (IVec2, HashMap<IVec2, Boulder>)> {
-    let (input, rows) = separated_list1(complete::line_ending, is_a(".O#"))(input)?;
-    let max_rows = i32::try_from(rows.len()).expect("stuff and things");
-    let max_cols = i32::try_from(rows[0].len()).expect("things and stuff?");
-    let maxs = IVec2::from((max_cols, max_rows));
-    let hash = rows
-        .iter()
-        .enumerate()
-        .flat_map(|(line_no, chars)| {
-            chars
-                .chars()
-                .enumerate()
-                .filter_map(move |(col_no, c)| {
-                    (c != '.').then_some((
-                        IVec2::from((
-                            i32::try_from(col_no).expect("hopefully not to small"),
-                            i32::try_from(line_no).expect("this shouldn't be too big"),
-                        )),
-                        c,
-                    ))
-                })
-                .map(|(pos, c)| (pos, Boulder::from(c)))
-        })
-        .collect();
-    Ok((input, (maxs, hash)))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "O....#....
 O.OO#....#
@@ -239,7 +200,7 @@ O.#..O.#.#
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "64".to_string());
     }
 }
@@ -0,0 +1,213 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! The Holiday ASCII String Helper and the lens boxes it hashes labels into.
+
+use std::fmt;
+
+const BOXES: usize = 256;
+
+/// the HASH algorithm from the puzzle
+#[must_use]
+pub fn holiday_hash(input: &str) -> u8 {
+    input
+        .bytes()
+        .fold(0, |acc: u8, x| acc.wrapping_add(x).wrapping_mul(17))
+}
+
+/// A map keeping its entries in 256 boxes picked by [`holiday_hash`], each
+/// box in the order its labels first went in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayHashMap<V> {
+    boxes: Vec<Vec<(String, V)>>,
+}
+
+impl<V> Default for HolidayHashMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> HolidayHashMap<V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            boxes: std::iter::repeat_with(Vec::new).take(BOXES).collect(),
+        }
+    }
+
+    fn bucket(&self, label: &str) -> &Vec<(String, V)> {
+        &self.boxes[usize::from(holiday_hash(label))]
+    }
+
+    /// Puts `value` under `label`, replacing the old value in its place if
+    /// there was one and going to the back of its box if not.
+    pub fn insert(&mut self, label: &str, value: V) -> Option<V> {
+        let bucket = &mut self.boxes[usize::from(holiday_hash(label))];
+        if let Some((_, old)) = bucket.iter_mut().find(|(l, _)| l == label) {
+            return Some(std::mem::replace(old, value));
+        }
+        bucket.push((label.to_string(), value));
+        None
+    }
+
+    /// takes `label` out, moving everything behind it in its box forward
+    pub fn remove(&mut self, label: &str) -> Option<V> {
+        let bucket = &mut self.boxes[usize::from(holiday_hash(label))];
+        let index = bucket.iter().position(|(l, _)| l == label)?;
+        Some(bucket.remove(index).1)
+    }
+
+    #[must_use]
+    pub fn get(&self, label: &str) -> Option<&V> {
+        self.bucket(label)
+            .iter()
+            .find_map(|(l, value)| (l == label).then_some(value))
+    }
+
+    /// every entry as its box, its slot in the box, label and value, box by
+    /// box
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &str, &V)> {
+        self.boxes.iter().enumerate().flat_map(|(box_num, bucket)| {
+            bucket
+                .iter()
+                .enumerate()
+                .map(move |(slot, (label, value))| (box_num, slot, label.as_str(), value))
+        })
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.boxes.iter().map(Vec::len).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.boxes.iter().all(Vec::is_empty)
+    }
+}
+
+impl<V: Copy + Into<usize>> HolidayHashMap<V> {
+    /// the sum of each lens's box number, slot and focal length, all
+    /// counting from one
+    #[must_use]
+    pub fn focusing_power(&self) -> usize {
+        self.iter()
+            .map(|(box_num, slot, _, value)| (box_num + 1) * (slot + 1) * (*value).into())
+            .sum()
+    }
+}
+
+/// The boxes with anything in them the way the puzzle draws them.
+impl<V: fmt::Display> fmt::Display for HolidayHashMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (box_num, bucket) in self.boxes.iter().enumerate() {
+            if bucket.is_empty() {
+                continue;
+            }
+            write!(f, "Box {box_num}:")?;
+            for (label, value) in bucket {
+                write!(f, " [{label} {value}]")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Set(u8),
+    Remove,
+}
+
+/// One step of the initialization sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub label: &'a str,
+    pub op: Op,
+}
+
+impl Step<'_> {
+    pub fn apply(&self, boxes: &mut HolidayHashMap<u8>) {
+        match self.op {
+            Op::Set(power) => {
+                boxes.insert(self.label, power);
+            }
+            Op::Remove => {
+                boxes.remove(self.label);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.op {
+            Op::Set(power) => write!(f, "{}={power}", self.label),
+            Op::Remove => write!(f, "{}-", self.label),
+        }
+    }
+}
+
+/// Runs the steps, drawing the boxes after each one like the puzzle does.
+#[must_use]
+pub fn trace(steps: &[Step<'_>]) -> String {
+    let mut boxes = HolidayHashMap::new();
+    steps
+        .iter()
+        .map(|step| {
+            step.apply(&mut boxes);
+            format!("After \"{step}\":\n{boxes}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::{parse, parse_steps};
+
+    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+    #[test]
+    fn keeps_insertion_order() {
+        let mut boxes = HolidayHashMap::new();
+        assert_eq!(boxes.insert("rn", 1_u8), None);
+        assert_eq!(boxes.insert("cm", 2), None);
+        assert_eq!(boxes.insert("rn", 5), Some(1));
+        assert_eq!(boxes.get("rn"), Some(&5));
+        assert_eq!(
+            boxes.iter().collect::<Vec<_>>(),
+            vec![(0, 0, "rn", &5), (0, 1, "cm", &2)]
+        );
+        assert_eq!(boxes.remove("rn"), Some(5));
+        assert_eq!(boxes.remove("rn"), None);
+        assert_eq!(boxes.get("rn"), None);
+        assert_eq!(boxes.iter().collect::<Vec<_>>(), vec![(0, 0, "cm", &2)]);
+        assert_eq!(boxes.len(), 1);
+        assert_eq!(boxes.focusing_power(), 2);
+    }
+
+    #[test]
+    fn traces_like_the_puzzle() {
+        let trace = trace(&parse_steps(&parse(INPUT)));
+        assert!(trace.starts_with(
+            "After \"rn=1\":
+Box 0: [rn 1]
+
+After \"cm-\":
+Box 0: [rn 1]
+
+After \"qp=3\":
+Box 0: [rn 1]
+Box 1: [qp 3]
+"
+        ));
+        assert!(trace.ends_with(
+            "After \"ot=7\":
+Box 0: [rn 1] [cm 2]
+Box 3: [ot 7] [ab 5] [pc 6]
+"
+        ));
+    }
+}
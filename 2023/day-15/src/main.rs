@@ -1,12 +1,26 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_15::parse;
+use day_15::parse_steps;
 use day_15::part1;
 use day_15::part2;
+use day_15::trace;
 
 fn main() {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let raw = parse(input);
+    let steps = parse_steps(&raw);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&raw);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    if std::env::args().any(|arg| arg == "--trace") {
+        println!("{}", trace(&steps));
+    }
+    let start = Instant::now();
+    let part2_result = part2(&steps);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
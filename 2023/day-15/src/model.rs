@@ -0,0 +1,56 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::all_consuming,
+    sequence::{pair, preceded},
+    IResult, Parser,
+};
+
+use crate::hashmap::{Op, Step};
+
+/// day 15 initialization sequence of aoc 2023, split into its raw steps
+///
+/// # Arguments
+/// - input the input for today's puzzle
+#[must_use]
+pub fn parse(input: &str) -> Vec<&str> {
+    input
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .collect()
+}
+
+/// reads each raw step as a lens being set or removed
+///
+/// # Arguments
+/// - raw the steps from [`parse`]
+///
+/// # Panics
+/// panics when a step isn't a label followed by `-` or `=` and a focal length
+#[must_use]
+pub fn parse_steps<'a>(raw: &[&'a str]) -> Vec<Step<'a>> {
+    raw.iter()
+        .map(|step| {
+            let (_, step) = parse_step(step).expect("aoc always good");
+            step
+        })
+        .collect()
+}
+
+fn parse_step(input: &str) -> IResult<&str, Step<'_>> {
+    all_consuming(
+        pair(
+            complete::alpha1,
+            alt((
+                tag("-").map(|_| Op::Remove),
+                preceded(tag("="), complete::u8).map(Op::Set),
+            )),
+        )
+        .map(|(label, op)| Step { label, op }),
+    )(input)
+}
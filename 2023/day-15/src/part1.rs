@@ -1,24 +1,18 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use crate::holiday_hash;
+
 /// day 15 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
-///
-/// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// - steps the raw steps of the initialization sequence
 #[must_use]
-pub fn part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|x| x.split(',').map(unhash).sum::<usize>().to_string())
-        .next()
-        .unwrap()
-}
-
-fn unhash(hash: &str) -> usize {
-    hash.chars()
-        .fold(0, |acc, x| (acc + (x as usize)) * 17 % 256)
+pub fn part1(steps: &[&str]) -> String {
+    steps
+        .iter()
+        .map(|step| usize::from(holiday_hash(step)))
+        .sum::<usize>()
+        .to_string()
 }
 
 #[cfg(test)]
@@ -26,6 +20,7 @@ mod test {
     use rstest::rstest;
 
     use super::*;
+    use crate::parse;
 
     #[rstest]
     #[case("HASH", 52)]
@@ -43,14 +38,19 @@ mod test {
     #[case("kqzb-\n", 127)]
     #[case("dx-", 153)]
     fn hash_test(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(unhash(input), expected);
+        assert_eq!(usize::from(holiday_hash(input)), expected);
     }
 
     const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT);
+        let result = part1(&parse(INPUT));
         assert_eq!(result, "1320".to_string());
     }
+
+    #[test]
+    fn part1_hashes_the_raw_steps() {
+        assert_eq!(part1(&parse("ab=07,HASH,rn=1\n")), "105");
+    }
 }
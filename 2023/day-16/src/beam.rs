@@ -0,0 +1,225 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use glam::IVec2;
+use petgraph::{algo::tarjan_scc, graph::NodeIndex, Graph};
+
+use crate::model::Gadget;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum FromDir {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl FromDir {
+    fn step(self) -> IVec2 {
+        match self {
+            FromDir::Left => IVec2::new(1, 0),
+            FromDir::Right => IVec2::new(-1, 0),
+            FromDir::Up => IVec2::new(0, 1),
+            FromDir::Down => IVec2::new(0, -1),
+        }
+    }
+}
+
+impl Gadget {
+    /// The directions a beam coming in `from` leaves this gadget.
+    fn bounce(self, from: FromDir) -> &'static [FromDir] {
+        match (self, from) {
+            (Gadget::None | Gadget::Horizontal, FromDir::Left)
+            | (Gadget::UlDr, FromDir::Up)
+            | (Gadget::UrDl, FromDir::Down) => &[FromDir::Left],
+            (Gadget::None | Gadget::Horizontal, FromDir::Right)
+            | (Gadget::UlDr, FromDir::Down)
+            | (Gadget::UrDl, FromDir::Up) => &[FromDir::Right],
+            (Gadget::None | Gadget::Vertical, FromDir::Up)
+            | (Gadget::UlDr, FromDir::Left)
+            | (Gadget::UrDl, FromDir::Right) => &[FromDir::Up],
+            (Gadget::None | Gadget::Vertical, FromDir::Down)
+            | (Gadget::UlDr, FromDir::Right)
+            | (Gadget::UrDl, FromDir::Left) => &[FromDir::Down],
+            (Gadget::Horizontal, FromDir::Up | FromDir::Down) => &[FromDir::Left, FromDir::Right],
+            (Gadget::Vertical, FromDir::Left | FromDir::Right) => &[FromDir::Up, FromDir::Down],
+        }
+    }
+}
+
+/// Every beam that can hit a gadget, as a graph.
+///
+/// Each node is a gadget together with the direction the beam came in from,
+/// and holds the tiles that beam lights up on its way to the next gadgets.
+/// Beams going round in circles end up in the same strongly connected
+/// component, so the tiles lit from every component are worked out once, in
+/// reverse topological order, and shared by every entry point that reaches it.
+pub(crate) struct BeamGraph<'a> {
+    gadgets: &'a HashMap<IVec2, Gadget>,
+    maxes: IVec2,
+    nodes: HashMap<(IVec2, FromDir), NodeIndex>,
+    component: Vec<usize>,
+    lit: Vec<Vec<u64>>,
+}
+
+impl<'a> BeamGraph<'a> {
+    pub fn new(gadgets: &'a HashMap<IVec2, Gadget>, maxes: IVec2) -> Self {
+        let mut graph = Graph::<Vec<usize>, ()>::new();
+        let nodes = gadgets
+            .keys()
+            .flat_map(|pos| {
+                [FromDir::Left, FromDir::Right, FromDir::Up, FromDir::Down].map(|from| (*pos, from))
+            })
+            .map(|node| (node, graph.add_node(Vec::new())))
+            .collect::<HashMap<_, _>>();
+        let mut beam_graph = Self {
+            gadgets,
+            maxes,
+            nodes,
+            component: Vec::new(),
+            lit: Vec::new(),
+        };
+        for (&(pos, from), &index) in &beam_graph.nodes {
+            let mut tiles = vec![beam_graph.tile(pos)];
+            for out in gadgets[&pos].bounce(from) {
+                let (run, next) = beam_graph.run(pos + out.step(), *out);
+                tiles.extend(run);
+                if let Some(next) = next {
+                    graph.add_edge(index, next, ());
+                }
+            }
+            graph[index] = tiles;
+        }
+
+        // tarjan hands back the components with the ones nothing leads out of first
+        let components = tarjan_scc(&graph);
+        let words = beam_graph.tile_count().div_ceil(64);
+        beam_graph.component = vec![0; graph.node_count()];
+        for (i, component) in components.iter().enumerate() {
+            for node in component {
+                beam_graph.component[node.index()] = i;
+            }
+        }
+        for (i, component) in components.iter().enumerate() {
+            let mut lit = vec![0_u64; words];
+            for node in component {
+                for tile in &graph[*node] {
+                    lit[tile / 64] |= 1 << (tile % 64);
+                }
+                for next in graph.neighbors(*node) {
+                    let next = beam_graph.component[next.index()];
+                    if next != i {
+                        lit.iter_mut()
+                            .zip(&beam_graph.lit[next])
+                            .for_each(|(a, b)| *a |= b);
+                    }
+                }
+            }
+            beam_graph.lit.push(lit);
+        }
+        beam_graph
+    }
+
+    fn tile_count(&self) -> usize {
+        usize::try_from(self.maxes.x * self.maxes.y).expect("grid to have a size")
+    }
+
+    fn tile(&self, pos: IVec2) -> usize {
+        usize::try_from(pos.y * self.maxes.x + pos.x).expect("tile to be on the grid")
+    }
+
+    fn on_grid(&self, pos: IVec2) -> bool {
+        pos.x >= 0 && pos.y >= 0 && pos.x < self.maxes.x && pos.y < self.maxes.y
+    }
+
+    /// Follows a beam from `pos` until it hits a gadget or leaves the grid,
+    /// returning the empty tiles it crossed and the gadget it hit.
+    fn run(&self, mut pos: IVec2, from: FromDir) -> (Vec<usize>, Option<NodeIndex>) {
+        let mut tiles = Vec::new();
+        while self.on_grid(pos) {
+            if self.gadgets.contains_key(&pos) {
+                return (tiles, Some(self.nodes[&(pos, from)]));
+            }
+            tiles.push(self.tile(pos));
+            pos += from.step();
+        }
+        (tiles, None)
+    }
+
+    /// How many tiles are energised by a beam entering at `start_pos`.
+    pub fn energised(&self, start_pos: IVec2, from: FromDir) -> usize {
+        let (tiles, next) = self.run(start_pos, from);
+        let mut lit = next.map_or_else(
+            || vec![0_u64; self.tile_count().div_ceil(64)],
+            |next| self.lit[self.component[next.index()]].clone(),
+        );
+        for tile in tiles {
+            lit[tile / 64] |= 1 << (tile % 64);
+        }
+        lit.iter().map(|x| x.count_ones() as usize).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::model::{parse, Contraption};
+
+    const INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    /// follows every beam tile by tile
+    fn walk(
+        gadgets: &HashMap<IVec2, Gadget>,
+        maxes: IVec2,
+        start_pos: IVec2,
+        from: FromDir,
+    ) -> usize {
+        let on_grid = |pos: IVec2| pos.cmpge(IVec2::ZERO).all() && pos.cmplt(maxes).all();
+        let mut seen = HashSet::new();
+        let mut queue = vec![(start_pos, from)];
+        while let Some((pos, from)) = queue.pop() {
+            if !on_grid(pos) || !seen.insert((pos, from)) {
+                continue;
+            }
+            let gadget = gadgets.get(&pos).copied().unwrap_or(Gadget::None);
+            for next in gadget.bounce(from) {
+                queue.push((pos + next.step(), *next));
+            }
+        }
+        seen.into_iter()
+            .map(|(pos, _)| pos)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    #[test]
+    fn matches_walking_the_beams() {
+        let Contraption { gadgets, maxes } = parse(INPUT);
+        let beams = BeamGraph::new(&gadgets, maxes);
+        assert_eq!(walk(&gadgets, maxes, IVec2::ZERO, FromDir::Left), 46);
+        for x in 0..maxes.x {
+            for y in 0..maxes.y {
+                for from in [FromDir::Left, FromDir::Right, FromDir::Up, FromDir::Down] {
+                    let start_pos = IVec2::new(x, y);
+                    assert_eq!(
+                        beams.energised(start_pos, from),
+                        walk(&gadgets, maxes, start_pos, from),
+                        "{start_pos} {from:?}"
+                    );
+                }
+            }
+        }
+    }
+}
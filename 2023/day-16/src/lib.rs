@@ -1,3 +1,6 @@
+mod beam;
+pub mod model;
+pub use crate::model::*;
 pub mod part1;
 pub use crate::part1::*;
 pub mod part2;
@@ -0,0 +1,81 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use glam::IVec2;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::eof,
+    multi::{fold_many1, many1},
+    sequence::terminated,
+    IResult, Parser,
+};
+use nom_locate::LocatedSpan;
+
+type Span<'a> = LocatedSpan<&'a str>;
+type SpanIVec2<'a> = LocatedSpan<&'a str, IVec2>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Gadget {
+    Horizontal,
+    Vertical,
+    UlDr,
+    UrDl,
+    None,
+}
+
+/// The mirrors and splitters in the cave, empty tiles left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contraption {
+    pub gadgets: HashMap<IVec2, Gadget>,
+    /// how many columns and rows the grid has
+    pub maxes: IVec2,
+}
+
+/// day 16 contraption of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when the grid has something other than `.`, `-`, `|`, `\` and `/` in it
+#[must_use]
+pub fn parse(input: &str) -> Contraption {
+    let (_, (gadgets, maxes)) = parse_input(Span::new(input)).expect("always aoc");
+    Contraption { gadgets, maxes }
+}
+
+fn with_xy(span: Span) -> SpanIVec2 {
+    let x = i32::try_from(span.get_column()).expect("overflow") - 1;
+    let y = i32::try_from(span.location_line()).expect("wrap around") - 1;
+    span.map_extra(|()| IVec2::new(x, y))
+}
+
+fn parse_gadget(input: Span) -> IResult<Span, (IVec2, Gadget)> {
+    alt((
+        tag("-").map(with_xy).map(|x| (x.extra, Gadget::Horizontal)),
+        tag("|").map(with_xy).map(|x| (x.extra, Gadget::Vertical)),
+        tag(r"\").map(with_xy).map(|x| (x.extra, Gadget::UlDr)),
+        tag(r"/").map(with_xy).map(|x| (x.extra, Gadget::UrDl)),
+        tag(".").map(with_xy).map(|x| (x.extra, Gadget::None)),
+    ))(input)
+}
+
+fn parse_input(input: Span) -> IResult<Span, (HashMap<IVec2, Gadget>, IVec2)> {
+    let (input, (gadgets, max_x, max_y)) = fold_many1(
+        terminated(many1(parse_gadget), alt((complete::line_ending, eof))),
+        || (HashMap::new(), 0, 0),
+        |(mut acc, _, max_y), row| {
+            let max_x = row.len().try_into().unwrap();
+            row.into_iter()
+                .filter(|(_, gadget)| *gadget != Gadget::None)
+                .for_each(|(pos, gadget)| {
+                    acc.insert(pos, gadget);
+                });
+            (acc, max_x, max_y + 1)
+        },
+    )(input)?;
+    Ok((input, (gadgets, IVec2::new(max_x, max_y))))
+}
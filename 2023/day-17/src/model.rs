@@ -0,0 +1,44 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use glam::IVec2;
+
+/// How much heat each city block loses, by column and row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeatMap {
+    /// how many columns and rows the city has
+    pub maxes: IVec2,
+    pub blocks: HashMap<IVec2, u32>,
+}
+
+/// day 17 heat map of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a block isn't a single digit or the city has no rows
+#[must_use]
+pub fn parse(input: &str) -> HeatMap {
+    let (maxes, blocks) = parse_input(input);
+    HeatMap { maxes, blocks }
+}
+
+fn parse_input(input: &str) -> (IVec2, HashMap<IVec2, u32>) {
+    let grid = input
+        .lines()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars().enumerate().map(move |(col, c)| {
+                (
+                    IVec2::new(col.try_into().unwrap(), row.try_into().unwrap()),
+                    c.to_digit(10).unwrap(),
+                )
+            })
+        })
+        .collect();
+    let max_row = i32::try_from(input.lines().count()).unwrap();
+    let max_col = i32::try_from(input.lines().next().unwrap().len()).unwrap();
+    (IVec2::new(max_col, max_row), grid)
+}
@@ -1,20 +1,23 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
 use glam::IVec2;
 use pathfinding::prelude::dijkstra;
 
-/// day 17 part 1 of aoc 2023
+use crate::model::HeatMap;
+
+/// day 17 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - city the parsed heat map
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// panics when there is no way across the city
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let (maxes, grid) = parse_input(input);
+pub fn part2(city: &HeatMap) -> String {
+    let maxes = city.maxes;
+    let grid = &city.blocks;
     let result = dijkstra(
         &(IVec2::splat(0), VecDeque::from([IVec2::splat(0)])),
         |(pos, lasts)| {
@@ -88,27 +91,10 @@ pub fn part2(input: &str) -> String {
     result.1.to_string()
 }
 
-fn parse_input(input: &str) -> (IVec2, HashMap<IVec2, u32>) {
-    let grid = input
-        .lines()
-        .enumerate()
-        .flat_map(|(row, line)| {
-            line.chars().enumerate().map(move |(col, c)| {
-                (
-                    IVec2::new(col.try_into().unwrap(), row.try_into().unwrap()),
-                    c.to_digit(10).unwrap(),
-                )
-            })
-        })
-        .collect();
-    let max_row = i32::try_from(input.lines().count()).unwrap();
-    let max_col = i32::try_from(input.lines().next().unwrap().len()).unwrap();
-    (IVec2::new(max_col, max_row), grid)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     use rstest::rstest;
 
@@ -138,7 +124,7 @@ mod test {
         "71"
     )]
     fn part2_works(#[case] input: &str, #[case] expected: &str) {
-        let result = part2(input);
+        let result = part2(&parse(input));
         assert_eq!(result, expected);
     }
 }
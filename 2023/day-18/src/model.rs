@@ -0,0 +1,69 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair},
+    IResult, Parser,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// One line of the dig plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub direction: Direction,
+    pub count: i64,
+    /// the hex digits of the colour, without the `#`
+    pub color: String,
+}
+
+/// day 18 dig plan of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a line isn't a direction, a distance and a `(#hex)` colour
+#[must_use]
+pub fn parse(input: &str) -> Vec<Step> {
+    let (_, steps) = parse_input(input).expect("valid aoc content not found");
+    steps
+}
+
+fn parse_step(input: &str) -> IResult<&str, Step> {
+    let (input, ((direction, count), color)) = separated_pair(
+        separated_pair(
+            alt((
+                tag("U").map(|_| Direction::Up),
+                tag("D").map(|_| Direction::Down),
+                tag("L").map(|_| Direction::Left),
+                tag("R").map(|_| Direction::Right),
+            )),
+            complete::space1,
+            complete::i64,
+        ),
+        complete::space1,
+        delimited(tag("("), preceded(tag("#"), complete::hex_digit1), tag(")")),
+    )(input)?;
+    Ok((
+        input,
+        Step {
+            direction,
+            count,
+            color: color.to_string(),
+        },
+    ))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Step>> {
+    separated_list1(complete::line_ending, parse_step)(input)
+}
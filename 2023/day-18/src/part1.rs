@@ -2,38 +2,18 @@
 
 use glam::I64Vec2;
 use itertools::Itertools;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    multi::separated_list1,
-    sequence::{delimited, preceded, separated_pair},
-    IResult, Parser,
-};
 
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-struct Step {
-    pub direction: Direction,
-    pub count: i64,
-    pub _color: String,
-}
+use crate::model::{Direction, Step};
 
 /// day 18 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - steps the parsed dig plan
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// panics when the plan is empty
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (_, steps) = parse_input(input).expect("valid aoc content not found");
+pub fn part1(steps: &[Step]) -> String {
     let corners = steps
         .iter()
         .scan(I64Vec2::splat(0), |cursor, next| {
@@ -61,49 +41,20 @@ pub fn part1(input: &str) -> String {
             let dist = (*b - *a).abs();
             dist.x + dist.y
         };
-    let area = (corners
+    let area = corners
         .iter()
         .tuple_windows()
         .map(|(a, b)| a.x * b.y - a.y * b.x)
         .sum::<i64>()
-        + perimeter)
-        / 2
+        .midpoint(perimeter)
         + 1;
     area.to_string()
 }
 
-fn parse_step(input: &str) -> IResult<&str, Step> {
-    let (input, ((direction, count), color)) = separated_pair(
-        separated_pair(
-            alt((
-                tag("U").map(|_| Direction::Up),
-                tag("D").map(|_| Direction::Down),
-                tag("L").map(|_| Direction::Left),
-                tag("R").map(|_| Direction::Right),
-            )),
-            complete::space1,
-            complete::i64,
-        ),
-        complete::space1,
-        delimited(tag("("), preceded(tag("#"), complete::hex_digit1), tag(")")),
-    )(input)?;
-    Ok((
-        input,
-        Step {
-            direction,
-            count,
-            _color: color.to_string(),
-        },
-    ))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Step>> {
-    separated_list1(complete::line_ending, parse_step)(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "R 6 (#70c710)
 D 5 (#0dc571)
@@ -122,7 +73,7 @@ U 2 (#7a21e3)";
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT);
+        let result = part1(&parse(INPUT));
         assert_eq!(result, "62".to_string());
     }
 }
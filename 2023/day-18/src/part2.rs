@@ -1,44 +1,43 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-//use std::collections::HashMap;
-
 use glam::I64Vec2;
 use itertools::Itertools;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    multi::separated_list1,
-    sequence::{delimited, preceded, separated_pair},
-    IResult, Parser,
-};
 
-#[derive(Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use crate::model::{Direction, Step};
 
-#[derive(Debug)]
-struct Step {
-    pub direction: Direction,
-    pub count: i64,
+impl Step {
+    /// The step the elves really meant, the first five hex digits of the
+    /// colour being the distance and the last one the direction.
+    fn decoded(&self) -> Self {
+        let direction = match self.color.chars().last().unwrap() {
+            '0' => Direction::Right,
+            '1' => Direction::Down,
+            '2' => Direction::Left,
+            '3' => Direction::Up,
+            x => unimplemented!("Direction for value {x} not iimplemented"),
+        };
+        let count = i64::from_str_radix(&self.color.chars().take(5).collect::<String>(), 16)
+            .expect("this should just you know work");
+        Self {
+            direction,
+            count,
+            color: self.color.clone(),
+        }
+    }
 }
 
 /// day 18 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - steps the parsed dig plan
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// panics when the plan is empty or a colour isn't a step
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let (_, steps) = parse_input(input).expect("valid aoc content not found");
+pub fn part2(steps: &[Step]) -> String {
     let corners = steps
         .iter()
+        .map(Step::decoded)
         .scan(I64Vec2::splat(0), |cursor, next| {
             let dir = match next.direction {
                 Direction::Up => I64Vec2::NEG_Y,
@@ -66,54 +65,22 @@ pub fn part2(input: &str) -> String {
             dist.x + dist.y
         };
 
-    let area = ((corners
+    let area = corners
         .iter()
         .tuple_windows()
         .map(|(a, b)| a.x * b.y - a.y * b.x)
         .sum::<i64>()
-        + perimeter)
-        / 2)
-    .abs()
+        .midpoint(perimeter)
+        .abs()
         + 1;
 
     (area).to_string()
 }
 
-fn parse_step(input: &str) -> IResult<&str, Step> {
-    let (input, (_, color)) = separated_pair(
-        separated_pair(
-            alt((
-                tag("U").map(|_| Direction::Up),
-                tag("D").map(|_| Direction::Down),
-                tag("L").map(|_| Direction::Left),
-                tag("R").map(|_| Direction::Right),
-            )),
-            complete::space1,
-            complete::i32,
-        ),
-        complete::space1,
-        delimited(tag("("), preceded(tag("#"), complete::hex_digit1), tag(")")),
-    )(input)?;
-    let direction = match color.chars().last().unwrap() {
-        '0' => Direction::Right,
-        '1' => Direction::Down,
-        '2' => Direction::Left,
-        '3' => Direction::Up,
-        x => unimplemented!("Direction for value {x} not iimplemented"),
-    };
-    let count = i64::from_str_radix(&color.chars().take(5).collect::<String>(), 16)
-        .expect("this should just you know work");
-
-    Ok((input, Step { direction, count }))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Step>> {
-    separated_list1(complete::line_ending, parse_step)(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "R 6 (#70c710)
 D 5 (#0dc571)
@@ -132,7 +99,7 @@ U 2 (#7a21e3)";
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "952408144115".to_string());
     }
 }
@@ -0,0 +1,56 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    IResult, Parser,
+};
+
+use crate::workflow::{parse_workflows, Part, Workflows};
+
+/// The workflows and the parts waiting to go through them.
+#[derive(Debug, Clone)]
+pub struct System<'a> {
+    pub workflows: Workflows<'a>,
+    pub parts: Vec<Part>,
+}
+
+/// day 19 workflows and parts of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when it cannot parse the input
+#[must_use]
+pub fn parse(input: &str) -> System<'_> {
+    let (_, (workflows, parts)) = parse_input(input).expect("valid aoc input");
+    System { workflows, parts }
+}
+
+fn parse_rating(input: &str) -> IResult<&str, Part> {
+    delimited(
+        tag("{"),
+        tuple((
+            delimited(tag("x="), complete::u32, tag(",")),
+            delimited(tag("m="), complete::u32, tag(",")),
+            delimited(tag("a="), complete::u32, tag(",")),
+            preceded(tag("s="), complete::u32),
+        ))
+        .map(|(x, m, a, s)| Part { x, m, a, s }),
+        tag("}"),
+    )(input)
+}
+
+fn parse_parts(input: &str) -> IResult<&str, Vec<Part>> {
+    separated_list1(complete::line_ending, parse_rating)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Workflows<'_>, Vec<Part>)> {
+    let (input, workflows) = parse_workflows(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, parts) = parse_parts(input)?;
+    Ok((input, (workflows, parts)))
+}
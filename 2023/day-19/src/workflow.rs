@@ -0,0 +1,540 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::{collections::HashMap, fmt::Display, ops::Range};
+
+use aoc_dot::{Edge, Node, ToDot};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    multi::{fold_many1, separated_list1},
+    sequence::{delimited, separated_pair, terminated},
+    IResult, Parser,
+};
+
+/// The lowest and one past the highest value any rating can have.
+const RATING_BOUNDS: Range<u32> = 1..4001;
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct Part {
+    pub x: u32,
+    pub m: u32,
+    pub a: u32,
+    pub s: u32,
+}
+
+impl Part {
+    #[must_use]
+    pub fn rating(&self) -> u32 {
+        self.x + self.m + self.a + self.s
+    }
+
+    #[must_use]
+    pub fn get(&self, rating_type: RatingType) -> u32 {
+        match rating_type {
+            RatingType::ExtremelyCool => self.x,
+            RatingType::Musical => self.m,
+            RatingType::AeroDynamic => self.a,
+            RatingType::Shiny => self.s,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum RatingType {
+    ExtremelyCool,
+    Musical,
+    AeroDynamic,
+    Shiny,
+}
+
+impl RatingType {
+    fn index(self) -> usize {
+        match self {
+            RatingType::ExtremelyCool => 0,
+            RatingType::Musical => 1,
+            RatingType::AeroDynamic => 2,
+            RatingType::Shiny => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+enum OpLabel<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
+enum Op<'a> {
+    Goto(OpLabel<'a>),
+    Greater(RatingType, u32, OpLabel<'a>),
+    Less(RatingType, u32, OpLabel<'a>),
+}
+
+impl Display for RatingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rating = match self {
+            Self::ExtremelyCool => "x",
+            Self::Musical => "m",
+            Self::AeroDynamic => "a",
+            Self::Shiny => "s",
+        };
+        write!(f, "{rating}")
+    }
+}
+
+impl Display for OpLabel<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Accept => write!(f, "A"),
+            Self::Reject => write!(f, "R"),
+            Self::Workflow(label) => write!(f, "{label}"),
+        }
+    }
+}
+
+/// The workflows by name, as parsed out of the top of the input.
+#[derive(Debug, Clone)]
+pub struct Workflows<'a>(HashMap<OpLabel<'a>, Vec<Op<'a>>>);
+
+impl<'a> Workflows<'a> {
+    /// parses just the workflows, ignoring the parts
+    #[must_use]
+    pub fn parse(input: &'a str) -> Option<Self> {
+        parse_workflows(input).ok().map(|(_, workflows)| workflows)
+    }
+}
+
+/// Every workflow is a box with an edge for each rule in the order they are
+/// checked, labeled with the rule's condition.
+impl ToDot for Workflows<'_> {
+    fn dot_nodes(&self) -> Vec<Node> {
+        let mut labels = self.0.keys().collect::<Vec<_>>();
+        labels.sort();
+        labels
+            .into_iter()
+            .map(|label| Node::new(label.to_string()).shape("box"))
+            .chain([
+                Node::new("A").attribute("color", "green"),
+                Node::new("R").attribute("color", "red"),
+            ])
+            .collect()
+    }
+
+    fn dot_edges(&self) -> Vec<Edge> {
+        let mut workflows = self.0.iter().collect::<Vec<_>>();
+        workflows.sort();
+        workflows
+            .into_iter()
+            .flat_map(|(from, ops)| {
+                ops.iter().map(move |op| match op {
+                    Op::Goto(to) => Edge::new(from.to_string(), to.to_string()),
+                    Op::Greater(rating, value, to) => Edge::new(from.to_string(), to.to_string())
+                        .label(format!("{rating}>{value}")),
+                    Op::Less(rating, value, to) => Edge::new(from.to_string(), to.to_string())
+                        .label(format!("{rating}<{value}")),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A range of values for each rating, every part inside it gets the same
+/// answer from the workflows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperRectangle {
+    pub x: Range<u32>,
+    pub m: Range<u32>,
+    pub a: Range<u32>,
+    pub s: Range<u32>,
+}
+
+impl HyperRectangle {
+    fn from_bounds([x, m, a, s]: [Range<u32>; 4]) -> Self {
+        Self { x, m, a, s }
+    }
+
+    /// How many different parts fit inside.
+    #[must_use]
+    pub fn volume(&self) -> u64 {
+        [&self.x, &self.m, &self.a, &self.s]
+            .into_iter()
+            .map(|range| u64::from(range.end - range.start))
+            .product()
+    }
+
+    #[must_use]
+    pub fn contains(&self, part: &Part) -> bool {
+        self.x.contains(&part.x)
+            && self.m.contains(&part.m)
+            && self.a.contains(&part.a)
+            && self.s.contains(&part.s)
+    }
+}
+
+/// The workflows starting at `in` flattened into one tree of comparisons.
+///
+/// Every comparison is normalised to `rating < below`, branches that can't be
+/// reached given the comparisons above them are dropped, and comparisons
+/// whose two sides end up the same are replaced by that side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecisionTree {
+    Accept,
+    Reject,
+    Split {
+        rating: RatingType,
+        below: u32,
+        low: Box<DecisionTree>,
+        high: Box<DecisionTree>,
+    },
+}
+
+impl DecisionTree {
+    /// Compiles the workflows from `in`, `None` when a workflow sends parts
+    /// to one that doesn't exist or round in a loop.
+    #[must_use]
+    pub fn compile(workflows: &Workflows) -> Option<Self> {
+        let mut visiting = Vec::new();
+        Self::compile_rules(
+            workflows,
+            OpLabel::Workflow("in"),
+            0,
+            std::array::from_fn(|_| RATING_BOUNDS),
+            &mut visiting,
+        )
+    }
+
+    /// Compiles the rules of `label` from the `rule`th on, for parts that fit
+    /// in `bounds`.
+    fn compile_rules<'a>(
+        workflows: &Workflows<'a>,
+        label: OpLabel<'a>,
+        rule: usize,
+        bounds: [Range<u32>; 4],
+        visiting: &mut Vec<OpLabel<'a>>,
+    ) -> Option<Self> {
+        let op = match label {
+            OpLabel::Accept => return Some(Self::Accept),
+            OpLabel::Reject => return Some(Self::Reject),
+            OpLabel::Workflow(_) => workflows.0.get(&label)?.get(rule)?,
+        };
+        // only the first rule enters the workflow, the rest just carry on
+        let entering = rule == 0;
+        if entering {
+            if visiting.contains(&label) {
+                return None;
+            }
+            visiting.push(label);
+        }
+        let (rating, below, low, high) = match *op {
+            Op::Goto(next) => {
+                let tree = Self::compile_rules(workflows, next, 0, bounds, visiting);
+                if entering {
+                    visiting.pop();
+                }
+                return tree;
+            }
+            Op::Less(rating, value, next) => (rating, value, Some(next), None),
+            Op::Greater(rating, value, next) => {
+                let Some(below) = value.checked_add(1) else {
+                    // no rating is ever above the largest one, so carry on
+                    let tree = Self::compile_rules(workflows, label, rule + 1, bounds, visiting);
+                    if entering {
+                        visiting.pop();
+                    }
+                    return tree;
+                };
+                (rating, below, None, Some(next))
+            }
+        };
+        let range = &bounds[rating.index()];
+        let mut branch = |target: Option<OpLabel<'a>>, range: Range<u32>| {
+            let mut bounds = bounds.clone();
+            bounds[rating.index()] = range;
+            match target {
+                Some(next) => Self::compile_rules(workflows, next, 0, bounds, visiting),
+                None => Self::compile_rules(workflows, label, rule + 1, bounds, visiting),
+            }
+        };
+        let tree = if below <= range.start {
+            branch(high, range.clone())
+        } else if below >= range.end {
+            branch(low, range.clone())
+        } else {
+            let low_tree = branch(low, range.start..below);
+            let high_tree = branch(high, below..range.end);
+            match (low_tree, high_tree) {
+                (Some(low), Some(high)) if low == high => Some(low),
+                (Some(low), Some(high)) => Some(Self::Split {
+                    rating,
+                    below,
+                    low: Box::new(low),
+                    high: Box::new(high),
+                }),
+                _ => None,
+            }
+        };
+        if entering {
+            visiting.pop();
+        }
+        tree
+    }
+
+    #[must_use]
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut tree = self;
+        loop {
+            match tree {
+                Self::Accept => return true,
+                Self::Reject => return false,
+                Self::Split {
+                    rating,
+                    below,
+                    low,
+                    high,
+                } => {
+                    tree = if part.get(*rating) < *below {
+                        low
+                    } else {
+                        high
+                    };
+                }
+            }
+        }
+    }
+
+    /// Every combination of ratings that gets accepted, as hyper-rectangles
+    /// that don't overlap.
+    #[must_use]
+    pub fn accepted(&self) -> Vec<HyperRectangle> {
+        let mut accepted = Vec::new();
+        let mut stack = vec![(self, std::array::from_fn(|_| RATING_BOUNDS))];
+        while let Some((tree, bounds)) = stack.pop() {
+            match tree {
+                Self::Accept => accepted.push(HyperRectangle::from_bounds(bounds)),
+                Self::Reject => {}
+                Self::Split {
+                    rating,
+                    below,
+                    low,
+                    high,
+                } => {
+                    let range = &bounds[rating.index()];
+                    let mut low_bounds = bounds.clone();
+                    low_bounds[rating.index()] = range.start..*below;
+                    let mut high_bounds = bounds.clone();
+                    high_bounds[rating.index()] = *below..range.end;
+                    stack.push((high, high_bounds));
+                    stack.push((low, low_bounds));
+                }
+            }
+        }
+        accepted
+    }
+
+    /// How many combinations of ratings get accepted.
+    #[must_use]
+    pub fn accepted_combinations(&self) -> u64 {
+        self.accepted().iter().map(HyperRectangle::volume).sum()
+    }
+}
+
+fn parse_op_label(input: &str) -> IResult<&str, OpLabel<'_>> {
+    alt((
+        tag("A").map(|_| OpLabel::Accept),
+        tag("R").map(|_| OpLabel::Reject),
+        complete::alpha1.map(OpLabel::Workflow),
+    ))(input)
+}
+
+fn parse_rating_type(input: &str) -> IResult<&str, RatingType> {
+    alt((
+        tag("x").map(|_| RatingType::ExtremelyCool),
+        tag("m").map(|_| RatingType::Musical),
+        tag("a").map(|_| RatingType::AeroDynamic),
+        tag("s").map(|_| RatingType::Shiny),
+    ))(input)
+}
+
+fn parse_op(input: &str) -> IResult<&str, Op<'_>> {
+    alt((
+        separated_pair(
+            separated_pair(parse_rating_type, tag("<"), complete::u32),
+            tag(":"),
+            parse_op_label,
+        )
+        .map(|((typ, value), to)| Op::Less(typ, value, to)),
+        separated_pair(
+            separated_pair(parse_rating_type, tag(">"), complete::u32),
+            tag(":"),
+            parse_op_label,
+        )
+        .map(|((typ, value), to)| Op::Greater(typ, value, to)),
+        parse_op_label.map(Op::Goto),
+    ))(input)
+}
+
+fn parse_workflow(input: &str) -> IResult<&str, (OpLabel<'_>, Vec<Op<'_>>)> {
+    let (input, label) =
+        complete::alpha1(input).map(|(input, label)| (input, OpLabel::Workflow(label)))?;
+    let (input, ops) = delimited(tag("{"), separated_list1(tag(","), parse_op), tag("}"))(input)?;
+    Ok((input, (label, ops)))
+}
+
+pub(crate) fn parse_workflows(input: &str) -> IResult<&str, Workflows<'_>> {
+    fold_many1(
+        terminated(parse_workflow, complete::line_ending),
+        HashMap::new,
+        |mut acc, (label, ops)| {
+            acc.insert(label, ops);
+            acc
+        },
+    )
+    .map(Workflows)
+    .parse(input)
+}
+
+#[cfg(test)]
+mod test {
+    use std::iter::successors;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::model::{parse, System};
+
+    const INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    /// Runs a part through the workflows one rule at a time.
+    fn follow_workflows(workflows: &Workflows, part: &Part) -> bool {
+        successors(Some(OpLabel::Workflow("in")), |label| {
+            workflows.0.get(label)?.iter().find_map(|op| match *op {
+                Op::Goto(next) => Some(next),
+                Op::Greater(rating, value, next) => (part.get(rating) > value).then_some(next),
+                Op::Less(rating, value, next) => (part.get(rating) < value).then_some(next),
+            })
+        })
+        .last()
+            == Some(OpLabel::Accept)
+    }
+
+    #[test]
+    fn tree_is_pruned() {
+        let workflows = parse(INPUT).workflows;
+        let tree = DecisionTree::compile(&workflows).unwrap();
+        let mut splits = 0;
+        let mut stack = vec![&tree];
+        while let Some(node) = stack.pop() {
+            if let DecisionTree::Split { low, high, .. } = node {
+                assert_ne!(low, high);
+                splits += 1;
+                stack.push(low);
+                stack.push(high);
+            }
+        }
+        // lnx and gd always end the same way, which makes qs always accept too
+        assert_eq!(splits, 11);
+
+        let workflows = Workflows::parse("in{x<10:a,R}\na{x>20:R,m<5:A,R}\n").unwrap();
+        assert_eq!(
+            DecisionTree::compile(&workflows),
+            Some(DecisionTree::Split {
+                rating: RatingType::ExtremelyCool,
+                below: 10,
+                low: Box::new(DecisionTree::Split {
+                    rating: RatingType::Musical,
+                    below: 5,
+                    low: Box::new(DecisionTree::Accept),
+                    high: Box::new(DecisionTree::Reject),
+                }),
+                high: Box::new(DecisionTree::Reject),
+            })
+        );
+    }
+
+    #[test]
+    fn bad_workflows_dont_compile() {
+        let workflows = Workflows::parse("in{x<10:a,R}\n").unwrap();
+        assert_eq!(DecisionTree::compile(&workflows), None);
+        let workflows = Workflows::parse("in{x<10:a,R}\na{m<5:in,A}\n").unwrap();
+        assert_eq!(DecisionTree::compile(&workflows), None);
+    }
+
+    #[test]
+    fn largest_rating_never_matches() {
+        let workflows = Workflows::parse("in{x>4294967295:R,m<4294967295:a,R}\na{A}\n").unwrap();
+        let tree = DecisionTree::compile(&workflows).unwrap();
+        assert_eq!(tree, DecisionTree::Accept);
+    }
+
+    #[test]
+    fn tree_matches_workflows() {
+        let System { workflows, parts } = parse(INPUT);
+        let tree = DecisionTree::compile(&workflows).unwrap();
+        for part in parts {
+            assert_eq!(
+                tree.accepts(&part),
+                follow_workflows(&workflows, &part),
+                "{part:?}"
+            );
+        }
+        assert_eq!(tree.accepted_combinations(), 167_409_079_868_000);
+    }
+
+    proptest! {
+        #[test]
+        fn tree_matches_workflows_for_any_part(
+            x in RATING_BOUNDS,
+            m in RATING_BOUNDS,
+            a in RATING_BOUNDS,
+            s in RATING_BOUNDS,
+        ) {
+            let workflows = parse(INPUT).workflows;
+            let tree = DecisionTree::compile(&workflows).unwrap();
+            let part = Part { x, m, a, s };
+            let expected = follow_workflows(&workflows, &part);
+            prop_assert_eq!(tree.accepts(&part), expected);
+            prop_assert_eq!(
+                tree.accepted().iter().filter(|x| x.contains(&part)).count(),
+                usize::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn workflows_to_dot() {
+        let workflows = Workflows::parse("in{s<1351:px,R}\npx{a>2006:A,R}\n").unwrap();
+        assert_eq!(
+            workflows.to_dot(),
+            r#"digraph {
+    "in" [shape="box"];
+    "px" [shape="box"];
+    "A" [color="green"];
+    "R" [color="red"];
+    "in" -> "px" [label="s<1351"];
+    "in" -> "R";
+    "px" -> "A" [label="a>2006"];
+    "px" -> "R";
+}
+"#
+        );
+    }
+}
@@ -1,3 +1,5 @@
+pub mod model;
+pub use crate::model::parse;
 pub mod part1;
 pub use crate::part1::part1;
 pub mod part2;
@@ -1,5 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_2::parse;
 use day_2::part1;
 use day_2::part2;
 
@@ -8,17 +11,20 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 enum Day2Error {
-    #[error("Part 1 failed")]
-    Part1Error,
-    #[error("Part 2 failed")]
-    Part2Error,
+    #[error("Parsing failed")]
+    ParseError,
 }
 
 fn main() -> Result<(), Day2Error> {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input).change_context(Day2Error::Part1Error)?;
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input).change_context(Day2Error::Part2Error)?;
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let games = parse(input).change_context(Day2Error::ParseError)?;
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&games);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&games);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
     Ok(())
 }
@@ -0,0 +1,83 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::{Report, Result, ResultExt};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, newline},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Day2ModelError {
+    #[error("there was a problem parsing")]
+    ParseError,
+}
+
+#[derive(Debug)]
+pub struct Round {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+/// the games of day 2 of AOC 2023
+///
+/// # Arguments
+/// - input the puszzle input
+///
+/// # Errors
+/// errors whenever the input isn't parsable
+///
+/// # Panics
+/// panics when a block isn't red, green or blue
+pub fn parse(input: &str) -> Result<Vec<Game>, Day2ModelError> {
+    let (_, games) = process_input(input)
+        .map_err(|err| Report::from(err.to_owned()))
+        .change_context(Day2ModelError::ParseError)?;
+    Ok(games)
+}
+
+fn process_block(input: &str) -> nom::IResult<&str, (u32, String)> {
+    let (i, (cnt, color)) =
+        separated_pair(complete::u32, complete::space1, complete::alpha1)(input)?;
+    Ok((i, (cnt, color.to_owned())))
+}
+
+fn process_round(input: &str) -> nom::IResult<&str, Round> {
+    let (i, blocks) = separated_list1(tag(", "), process_block)(input)?;
+    let mut round = Round {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+    for (cnt, color) in blocks {
+        match color.as_str() {
+            "red" => round.red = cnt,
+            "green" => round.green = cnt,
+            "blue" => round.blue = cnt,
+            _ => panic!("this should be a color name"),
+        }
+    }
+    Ok((i, round))
+}
+
+fn process_game(input: &str) -> nom::IResult<&str, Game> {
+    let (i, (id, rounds)) = separated_pair(
+        preceded(tag("Game "), complete::u32),
+        tag(": "),
+        separated_list1(tag("; "), process_round),
+    )(input)?;
+    Ok((i, Game { id, rounds }))
+}
+
+fn process_input(input: &str) -> nom::IResult<&str, Vec<Game>> {
+    separated_list1(newline, process_game)(input)
+}
@@ -1,33 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use error_stack::{Report, Result, ResultExt};
 use log::debug;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, newline},
-    multi::separated_list1,
-    sequence::{preceded, separated_pair},
-};
-use thiserror::Error;
 
-#[derive(Debug, Error)]
-pub enum Day2Part1Error {
-    #[error("there was a problem parsing")]
-    ParseError,
-}
-
-#[derive(Debug)]
-struct Round {
-    pub red: u32,
-    pub green: u32,
-    pub blue: u32,
-}
-
-#[derive(Debug)]
-struct Game {
-    pub id: u32,
-    pub rounds: Vec<Round>,
-}
+use crate::model::Game;
 
 impl Game {
     fn to_part1(&self) -> Option<u32> {
@@ -46,65 +21,24 @@ impl Game {
     }
 }
 
-/// part2 of day 2 of AOC 2023
+/// part1 of day 2 of AOC 2023
 ///
 /// # Arguments
-/// - input the puszzle input
-///
-/// # Errors
-/// errors whenever the input isn't parsable
-pub fn part1(input: &'static str) -> Result<String, Day2Part1Error> {
-    let (_, games) = process_input(input)
-        .map_err(|err| Report::from(err.to_owned()))
-        .change_context(Day2Part1Error::ParseError)?;
+/// - games the parsed games
+#[must_use]
+pub fn part1(games: &[Game]) -> String {
     debug!("{games:?}");
-    Ok(games
+    games
         .iter()
         .filter_map(Game::to_part1)
         .sum::<u32>()
-        .to_string())
-}
-
-fn process_block(input: &str) -> nom::IResult<&str, (u32, String)> {
-    let (i, (cnt, color)) =
-        separated_pair(complete::u32, complete::space1, complete::alpha1)(input)?;
-    Ok((i, (cnt, color.to_owned())))
-}
-
-fn process_round(input: &str) -> nom::IResult<&str, Round> {
-    let (i, blocks) = separated_list1(tag(", "), process_block)(input)?;
-    let mut round = Round {
-        red: 0,
-        green: 0,
-        blue: 0,
-    };
-    for (cnt, color) in blocks {
-        match color.as_str() {
-            "red" => round.red = cnt,
-            "green" => round.green = cnt,
-            "blue" => round.blue = cnt,
-            _ => panic!("this should be a color name"),
-        };
-    }
-    Ok((i, round))
-}
-
-fn process_game(input: &str) -> nom::IResult<&str, Game> {
-    let (i, (id, rounds)) = separated_pair(
-        preceded(tag("Game "), complete::u32),
-        tag(": "),
-        separated_list1(tag("; "), process_round),
-    )(input)?;
-    Ok((i, Game { id, rounds }))
-}
-
-fn process_input(input: &str) -> nom::IResult<&str, Vec<Game>> {
-    separated_list1(newline, process_game)(input)
+        .to_string()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
 
     const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
@@ -115,7 +49,7 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part1_works() {
-        let result = part1(INPUT).unwrap();
+        let result = part1(&parse(INPUT).unwrap());
         assert_eq!(result, "8".to_string());
     }
 }
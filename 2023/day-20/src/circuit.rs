@@ -0,0 +1,432 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::BTreeMap;
+
+use aoc_dot::{Edge, Node, ToDot};
+use error_stack::{Report, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::opt,
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NetworkError {
+    #[error("could not parse the module network")]
+    ParseError,
+    #[error("{0} should be fed by exactly one conjunction")]
+    NoFinalConjunction(String),
+    #[error("{0} feeds the final conjunction but isn't a conjunction with a single input")]
+    NotAnInverter(String),
+    #[error("{0} doesn't hang off the broadcaster as a binary counter")]
+    NotACounter(String),
+    #[error("flip-flop {0} isn't wired up as a bit of a binary counter")]
+    BadBit(String),
+    #[error("conjunction {0} isn't wired up as the hub of a binary counter")]
+    BadHub(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleKind {
+    Broadcast,
+    FlipFlop,
+    Conjunction,
+}
+
+/// How the modules are wired together, without any of their state.
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    modules: BTreeMap<&'a str, (ModuleKind, Vec<&'a str>)>,
+    inputs: BTreeMap<&'a str, Vec<&'a str>>,
+}
+
+/// A chain of flip-flops counting button presses in binary, with a
+/// conjunction hub that fires once the count reaches `period` and resets it.
+///
+/// The bits set in `period` are the flip-flops wired into the hub, the rest
+/// are wired back out of it so the hub's low pulse tips the count over to
+/// zero again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<'a> {
+    pub hub: &'a str,
+    /// The flip-flops from the lowest bit up.
+    pub bits: Vec<&'a str>,
+    pub period: u64,
+}
+
+impl<'a> Network<'a> {
+    /// # Errors
+    /// - `ParseError` when the input isn't a list of modules
+    pub fn parse(input: &'a str) -> Result<Self, NetworkError> {
+        let (rest, lines) =
+            parse_input(input).map_err(|_| Report::new(NetworkError::ParseError))?;
+        if !rest.trim().is_empty() {
+            return Err(Report::new(NetworkError::ParseError));
+        }
+        let mut inputs = BTreeMap::<_, Vec<_>>::new();
+        for (label, (_, outputs)) in &lines {
+            for output in outputs {
+                inputs.entry(*output).or_default().push(*label);
+            }
+        }
+        Ok(Self {
+            modules: lines.into_iter().collect(),
+            inputs,
+        })
+    }
+
+    /// every module's label, in order
+    pub fn labels(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.modules.keys().copied()
+    }
+
+    #[must_use]
+    pub fn kind(&self, label: &str) -> Option<ModuleKind> {
+        self.modules.get(label).map(|(kind, _)| *kind)
+    }
+
+    #[must_use]
+    pub fn outputs(&self, label: &str) -> &[&'a str] {
+        self.modules
+            .get(label)
+            .map_or(&[], |(_, outputs)| outputs.as_slice())
+    }
+
+    #[must_use]
+    pub fn inputs(&self, label: &str) -> &[&'a str] {
+        self.inputs.get(label).map_or(&[], Vec::as_slice)
+    }
+
+    /// Splits the network into the counters that drive `sink`.
+    ///
+    /// `sink` has to be fed by a single conjunction, and each of its inputs
+    /// has to be an inverter on the hub of a counter started by the
+    /// broadcaster. `sink` gets a low pulse on the first press every counter
+    /// fires on together.
+    ///
+    /// # Errors
+    /// the first part of the network that doesn't fit that shape
+    pub fn counters(&self, sink: &str) -> Result<Vec<Counter<'a>>, NetworkError> {
+        let [last] = self.inputs(sink) else {
+            return Err(Report::new(NetworkError::NoFinalConjunction(
+                sink.to_string(),
+            )));
+        };
+        if self.kind(last) != Some(ModuleKind::Conjunction) {
+            return Err(Report::new(NetworkError::NoFinalConjunction(
+                sink.to_string(),
+            )));
+        }
+        let mut counters = self
+            .outputs("broadcaster")
+            .iter()
+            .map(|start| self.counter(start))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut feeding = Vec::new();
+        for inverter in self.inputs(last) {
+            let hub = match self.inputs(inverter) {
+                [hub] if self.kind(inverter) == Some(ModuleKind::Conjunction) => hub,
+                _ => {
+                    return Err(Report::new(NetworkError::NotAnInverter(
+                        (*inverter).to_string(),
+                    )))
+                }
+            };
+            let Some(i) = counters.iter().position(|x| x.hub == *hub) else {
+                return Err(Report::new(NetworkError::NotACounter((*hub).to_string())));
+            };
+            feeding.push(counters.swap_remove(i));
+        }
+        if let Some(unused) = counters.first() {
+            return Err(Report::new(NetworkError::NotACounter(
+                unused.hub.to_string(),
+            )));
+        }
+        Ok(feeding)
+    }
+
+    /// Reads the counter off the flip-flop chain starting at `start`.
+    fn counter(&self, start: &'a str) -> Result<Counter<'a>, NetworkError> {
+        let bad_bit = |label: &str| Report::new(NetworkError::BadBit(label.to_string()));
+        if self.kind(start) != Some(ModuleKind::FlipFlop) {
+            return Err(Report::new(NetworkError::NotACounter(start.to_string())));
+        }
+        let hub = match self
+            .outputs(start)
+            .iter()
+            .filter(|x| self.kind(x) == Some(ModuleKind::Conjunction))
+            .collect::<Vec<_>>()[..]
+        {
+            [hub] => *hub,
+            _ => return Err(bad_bit(start)),
+        };
+
+        let mut bits = Vec::new();
+        let mut period = 0_u64;
+        let mut current = Some(start);
+        while let Some(bit) = current {
+            if bits.len() == 64 || bits.contains(&bit) {
+                return Err(bad_bit(bit));
+            }
+            let mut next = None;
+            let mut feeds_hub = false;
+            for output in self.outputs(bit) {
+                match self.kind(output) {
+                    _ if *output == hub => feeds_hub = true,
+                    Some(ModuleKind::FlipFlop) if next.is_none() => next = Some(*output),
+                    _ => return Err(bad_bit(bit)),
+                }
+            }
+            let fed_by_hub = self.inputs(bit).contains(&hub);
+            // the lowest bit has to be both so the hub's pulse carries
+            // through every bit and back to zero
+            if (feeds_hub == fed_by_hub) != bits.is_empty() || (bits.is_empty() && !feeds_hub) {
+                return Err(bad_bit(bit));
+            }
+            if feeds_hub {
+                period |= 1 << bits.len();
+            }
+            bits.push(bit);
+            current = next;
+        }
+
+        let mut wired_in = bits
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| period & (1 << i) != 0)
+            .map(|(_, bit)| *bit);
+        let hub_inputs = self.inputs(hub);
+        if hub_inputs.len() != period.count_ones() as usize
+            || !wired_in.all(|bit| hub_inputs.contains(&bit))
+            || self
+                .outputs(hub)
+                .iter()
+                .filter(|x| !bits.contains(x))
+                .count()
+                != 1
+            || period & (1 << (bits.len() - 1)) == 0
+        {
+            return Err(Report::new(NetworkError::BadHub(hub.to_string())));
+        }
+        Ok(Counter { hub, bits, period })
+    }
+}
+
+/// Flip-flops are drawn as boxes and conjunctions as diamonds, so the
+/// counters stand out as a chain of boxes around a diamond.
+impl ToDot for Network<'_> {
+    fn dot_nodes(&self) -> Vec<Node> {
+        self.modules
+            .iter()
+            .map(|(label, (kind, _))| match kind {
+                ModuleKind::Broadcast => Node::new(*label).shape("doublecircle"),
+                ModuleKind::FlipFlop => Node::new(*label).label(format!("%{label}")).shape("box"),
+                ModuleKind::Conjunction => Node::new(*label)
+                    .label(format!("&{label}"))
+                    .shape("diamond"),
+            })
+            .collect()
+    }
+
+    fn dot_edges(&self) -> Vec<Edge> {
+        self.modules
+            .iter()
+            .flat_map(|(label, (_, outputs))| {
+                outputs.iter().map(|output| Edge::new(*label, *output))
+            })
+            .collect()
+    }
+}
+
+type Line<'a> = (&'a str, (ModuleKind, Vec<&'a str>));
+
+fn parse_line(input: &str) -> IResult<&str, Line<'_>> {
+    let (input, kind) = opt(alt((tag("%"), tag("&"))))(input)?;
+    let (input, (label, connections)) = separated_pair(
+        complete::alpha1,
+        tuple((complete::space0, tag("->"), complete::space0)),
+        separated_list1(tuple((tag(","), complete::space0)), complete::alpha1),
+    )(input)?;
+    let kind = match kind {
+        Some("%") => ModuleKind::FlipFlop,
+        Some("&") => ModuleKind::Conjunction,
+        _ => ModuleKind::Broadcast,
+    };
+    Ok((input, (label, (kind, connections))))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Line<'_>>> {
+    separated_list1(complete::line_ending, parse_line)(input)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, VecDeque};
+
+    use super::*;
+
+    /// Two counters, one firing every 5 presses and one every 7.
+    const INPUT: &str = "broadcaster -> aa, ba
+%aa -> ab, ha
+%ab -> ac
+%ac -> ha
+&ha -> ab, aa, ia
+&ia -> fin
+%ba -> bb, hb
+%bb -> bc, hb
+%bc -> hb
+&hb -> ba, ib
+&ib -> fin
+&fin -> rx";
+
+    /// Pushes the button until `rx` gets a low pulse.
+    fn presses_until_rx(network: &Network) -> u64 {
+        let mut on = BTreeSet::new();
+        let mut memory = BTreeMap::new();
+        for presses in 1.. {
+            let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+            while let Some((from, to, high)) = queue.pop_front() {
+                if to == "rx" && !high {
+                    return presses;
+                }
+                let send = match network.kind(to) {
+                    Some(ModuleKind::Broadcast) => high,
+                    Some(ModuleKind::FlipFlop) if !high => {
+                        if !on.remove(to) {
+                            on.insert(to);
+                        }
+                        on.contains(to)
+                    }
+                    Some(ModuleKind::Conjunction) => {
+                        memory.insert((to, from), high);
+                        !network
+                            .inputs(to)
+                            .iter()
+                            .all(|x| memory.get(&(to, *x)).copied().unwrap_or_default())
+                    }
+                    _ => continue,
+                };
+                for output in network.outputs(to) {
+                    queue.push_back((to, output, send));
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn counters_decode() {
+        let network = Network::parse(INPUT).unwrap();
+        let mut counters = network.counters("rx").unwrap();
+        counters.sort_by_key(|x| x.hub);
+        assert_eq!(
+            counters,
+            [
+                Counter {
+                    hub: "ha",
+                    bits: vec!["aa", "ab", "ac"],
+                    period: 5
+                },
+                Counter {
+                    hub: "hb",
+                    bits: vec!["ba", "bb", "bc"],
+                    period: 7
+                }
+            ]
+        );
+        assert_eq!(presses_until_rx(&network), 35);
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn periods_match_simulation() {
+        // a lone counter for every period that fits in 4 bits
+        for period in (9_u64..16).step_by(2) {
+            let bits = (0..4)
+                .map(|i| format!("b{}", (b'a' + i) as char))
+                .collect::<Vec<_>>();
+            let mut lines = vec!["broadcaster -> ba".to_string()];
+            for (i, bit) in bits.iter().enumerate() {
+                let mut outputs = bits.get(i + 1).into_iter().cloned().collect::<Vec<_>>();
+                if period & (1 << i) != 0 {
+                    outputs.push("hub".to_string());
+                }
+                lines.push(format!("%{bit} -> {}", outputs.join(", ")));
+            }
+            let mut hub_outputs = bits
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i == 0 || period & (1 << i) == 0)
+                .map(|(_, bit)| bit.clone())
+                .collect::<Vec<_>>();
+            hub_outputs.push("inv".to_string());
+            lines.push(format!("&hub -> {}", hub_outputs.join(", ")));
+            lines.push("&inv -> fin".to_string());
+            lines.push("&fin -> rx".to_string());
+            let input = lines.join("\n");
+
+            let network = Network::parse(&input).unwrap();
+            let counters = network.counters("rx").unwrap();
+            assert_eq!(counters.len(), 1);
+            assert_eq!(counters[0].period, period);
+            assert_eq!(presses_until_rx(&network), period);
+        }
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn network_to_dot() {
+        let network = Network::parse("broadcaster -> a\n%a -> con\n&con -> rx").unwrap();
+        assert_eq!(
+            network.to_dot(),
+            r#"digraph {
+    "a" [label="%a", shape="box"];
+    "broadcaster" [shape="doublecircle"];
+    "con" [label="&con", shape="diamond"];
+    "a" -> "con";
+    "broadcaster" -> "a";
+    "con" -> "rx";
+}
+"#
+        );
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn wrong_shapes_are_errors() {
+        let error = |input: &str| {
+            Network::parse(input)
+                .unwrap()
+                .counters("rx")
+                .unwrap_err()
+                .current_context()
+                .to_string()
+        };
+        assert_eq!(
+            error(&INPUT.replace("&fin -> rx", "%fin -> rx")),
+            "rx should be fed by exactly one conjunction"
+        );
+        assert_eq!(
+            error(&INPUT.replace("&ha -> ab, aa, ia", "&ha -> ab, aa, fin")),
+            "ha feeds the final conjunction but isn't a conjunction with a single input"
+        );
+        assert_eq!(
+            error(&INPUT.replace("%ab -> ac", "%ab -> ac, ha")),
+            "flip-flop ab isn't wired up as a bit of a binary counter"
+        );
+        assert_eq!(
+            error(&INPUT.replace("&hb -> ba, ib", "&hb -> ba, bc, ib")),
+            "flip-flop bc isn't wired up as a bit of a binary counter"
+        );
+        assert_eq!(
+            error(&INPUT.replace("&ib -> fin", "&ib -> ia")),
+            "ia feeds the final conjunction but isn't a conjunction with a single input"
+        );
+    }
+}
@@ -4,16 +4,46 @@
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
+use std::time::Instant;
+
+use day_20::parse;
 use day_20::part1;
 use day_20::part2;
 
-fn main() {
+use aoc_dot::ToDot;
+
+use error_stack::{Result, ResultExt};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
+enum Day20Error {
+    #[error("Parsing failed")]
+    ParseError,
+    #[error("Part 2 failed")]
+    Part2Error,
+    #[error("Writing the module network out as DOT failed")]
+    DotError,
+}
+
+fn main() -> Result<(), Day20Error> {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let network = parse(input).change_context(Day20Error::ParseError)?;
+    println!("parsed in {:?}", start.elapsed());
+    if let Some(path) = aoc_dot::path_from_args() {
+        network
+            .write_dot(path)
+            .change_context(Day20Error::DotError)?;
+    }
+    let start = Instant::now();
+    let part1_result = part1(&network);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&network).change_context(Day20Error::Part2Error)?;
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
+    Ok(())
 }
@@ -0,0 +1,23 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::{Result, ResultExt};
+use thiserror::Error;
+
+use crate::circuit::Network;
+
+#[derive(Debug, Error)]
+pub enum Day20ModelError {
+    #[error("Problem parsing Day 20")]
+    ParseError,
+}
+
+/// Day-20 module network of AC2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Errors
+/// - `ParseError` when the input isn't a list of modules
+pub fn parse(input: &str) -> Result<Network<'_>, Day20ModelError> {
+    Network::parse(input).change_context(Day20ModelError::ParseError)
+}
@@ -2,15 +2,7 @@
 
 use std::collections::{BTreeMap, VecDeque};
 
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    combinator::opt,
-    multi::separated_list1,
-    sequence::{separated_pair, tuple},
-    IResult,
-};
+use crate::circuit::{ModuleKind, Network};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum ModuleType<'a> {
@@ -111,6 +103,34 @@ fn push_button<'a>(setup: &mut BTreeMap<&'a str, Module<'a>>) -> (usize, usize)
     (low_signals, high_signals)
 }
 
+/// every module in the network with its memory all starting off low
+fn power_on<'a>(network: &Network<'a>) -> BTreeMap<&'a str, Module<'a>> {
+    network
+        .labels()
+        .map(|label| {
+            let mod_type = match network.kind(label) {
+                Some(ModuleKind::FlipFlop) => ModuleType::FlipFlop(false),
+                Some(ModuleKind::Conjunction) => ModuleType::Conjunction(
+                    network
+                        .inputs(label)
+                        .iter()
+                        .map(|input| (*input, false))
+                        .collect(),
+                ),
+                Some(ModuleKind::Broadcast) | None => ModuleType::Broadcast,
+            };
+            (
+                label,
+                Module {
+                    label,
+                    mod_type,
+                    connections: network.outputs(label).to_vec(),
+                },
+            )
+        })
+        .collect()
+}
+
 #[allow(dead_code)]
 fn setup_to_key(setup: &BTreeMap<&str, Module>) -> String {
     setup.values().map(Module::state_hash).collect::<String>()
@@ -119,13 +139,10 @@ fn setup_to_key(setup: &BTreeMap<&str, Module>) -> String {
 /// day 20 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
-///
-/// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// - network the parsed module network
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (_, mut setup) = parse_input(input).expect("aoc input always valid");
+pub fn part1(network: &Network<'_>) -> String {
+    let mut setup = power_on(network);
     let mut high_count = 0;
     let mut low_count = 0;
     for _ in 0..1000 {
@@ -137,74 +154,12 @@ pub fn part1(input: &str) -> String {
     (high_count * low_count).to_string()
 }
 
-fn parse_line(input: &str) -> IResult<&str, (&str, Module)> {
-    let (input, mod_type) = opt(alt((tag("%"), tag("&"))))(input)?;
-    let (input, (label, connections)) = separated_pair(
-        complete::alpha1,
-        tuple((complete::space0, tag("->"), complete::space0)),
-        separated_list1(tuple((tag(","), complete::space0)), complete::alpha1),
-    )(input)?;
-    let mod_type = match mod_type {
-        Some("%") => ModuleType::FlipFlop(false),
-        Some("&") => ModuleType::Conjunction(BTreeMap::new()),
-        None => ModuleType::Broadcast,
-        Some(x) => unimplemented!("No module type {x}"),
-    };
-    Ok((
-        input,
-        (
-            label,
-            Module {
-                label,
-                mod_type,
-                connections,
-            },
-        ),
-    ))
-}
-
-fn parse_input(input: &str) -> IResult<&str, BTreeMap<&str, Module>> {
-    let (input, mut lines) = separated_list1(complete::line_ending, parse_line)(input)
-        .map(|(input, v)| (input, v.into_iter().collect::<BTreeMap<_, _>>()))?;
-    let conjunctions = lines
-        .iter()
-        .filter_map(|(key, module)| {
-            if let ModuleType::Conjunction(_) = module.mod_type {
-                Some(*key)
-            } else {
-                None
-            }
-        })
-        .map(|conjunction| {
-            (
-                conjunction,
-                lines
-                    .iter()
-                    .filter_map(|(key, module)| {
-                        module.connections.contains(&conjunction).then_some(*key)
-                    })
-                    .collect::<Vec<_>>(),
-            )
-        })
-        .collect::<BTreeMap<_, _>>();
-    lines
-        .iter_mut()
-        .filter(|(key, _)| conjunctions.contains_key(*key))
-        .for_each(|(key, module)| {
-            conjunctions.get(key).unwrap().iter().for_each(|to_key| {
-                if let ModuleType::Conjunction(tos) = &mut module.mod_type {
-                    tos.insert(to_key, false);
-                }
-            });
-        });
-    Ok((input, lines))
-}
-
 #[cfg(test)]
 mod test {
     use rstest::rstest;
 
     use super::*;
+    use crate::parse;
 
     #[test_log::test(rstest)]
     #[case(
@@ -224,7 +179,7 @@ mod test {
         "11687500"
     )]
     fn part1_works(#[case] input: &str, #[case] expected: &str) {
-        let result = part1(input);
+        let result = part1(&parse(input).unwrap());
         assert_eq!(result, expected);
     }
 }
@@ -1,5 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_21::parse;
 use day_21::part1;
 use day_21::part2;
 
@@ -12,8 +15,13 @@ fn main() {
     let _profiler = dhat::Profiler::new_heap();
 
     let input = include_str!("./input.txt");
-    let part1_result = part1(input, 64);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input, 26_501_365);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let garden = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&garden, 64);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&garden, 26_501_365);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
@@ -0,0 +1,69 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashSet;
+
+use glam::IVec2;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::eof,
+    multi::{fold_many1, many1},
+    sequence::terminated,
+    IResult, Parser,
+};
+use nom_locate::LocatedSpan;
+
+type Span<'a> = LocatedSpan<&'a str>;
+type SpanIVec2<'a> = LocatedSpan<&'a str, IVec2>;
+
+/// The garden the elf is walking, one tile of it anyway.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garden {
+    pub start: IVec2,
+    /// how many columns and rows before the garden repeats
+    pub size: IVec2,
+    pub rocks: HashSet<IVec2>,
+}
+
+/// day 21 garden of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when the garden has something other than `S`, `.` and `#` in it
+#[must_use]
+pub fn parse(input: &str) -> Garden {
+    let (_, (start, size, rocks)) =
+        parse_input(Span::from(input)).expect("AOC input should be valid");
+    Garden { start, size, rocks }
+}
+
+fn with_xy(span: Span) -> SpanIVec2 {
+    let x = i32::try_from(span.get_column()).expect("overflow") - 1;
+    let y = i32::try_from(span.location_line()).expect("wrap around") - 1;
+    span.map_extra(|()| IVec2::new(x, y))
+}
+
+fn parse_input(input: Span) -> IResult<Span, (IVec2, IVec2, HashSet<IVec2>)> {
+    fold_many1(
+        terminated(
+            many1(alt((tag("S"), tag("."), tag("#"))).map(with_xy)),
+            alt((complete::line_ending, eof)),
+        ),
+        || (IVec2::splat(0), IVec2::splat(0), HashSet::new()),
+        |(mut start, mut size, mut set), row| {
+            for spot in row {
+                if spot.fragment() == &"S" {
+                    start = spot.extra;
+                }
+                if spot.fragment() == &"#" {
+                    set.insert(spot.extra);
+                }
+                size = size.max(spot.extra + 1);
+            }
+            (start, size, set)
+        },
+    )(input)
+}
@@ -2,20 +2,15 @@
 
 use std::{collections::HashSet, ops::Not};
 
+use day_9::PolynomialSequence;
+
 use glam::IVec2;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    combinator::eof,
-    multi::{fold_many1, many1},
-    sequence::terminated,
-    IResult, Parser,
-};
-use nom_locate::LocatedSpan;
 
-type Span<'a> = LocatedSpan<&'a str>;
-type SpanIVec2<'a> = LocatedSpan<&'a str, IVec2>;
+use crate::model::Garden;
+
+/// how many samples in a row have to fit a quadratic before trusting it,
+/// two more than the three that any quadratic fits
+const SETTLED_SAMPLES: usize = 5;
 
 fn next_step(
     loc: IVec2,
@@ -31,74 +26,53 @@ fn next_step(
 /// day 21 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - garden the parsed garden
+/// - steps how many steps the elf takes
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// panics when the counts never settle into a quadratic
 #[must_use]
-pub fn part2(input: &str, steps: usize) -> String {
-    let (_, (start, size, boulders)) =
-        parse_input(Span::from(input)).expect("AOC input should be valid");
+pub fn part2(garden: &Garden, steps: usize) -> String {
+    let Garden { start, size, rocks } = garden;
     let sq_size = usize::try_from(size.x).unwrap();
     let base = steps % sq_size;
     let reps = steps / sq_size;
-    let mut current = [start].into_iter().collect::<HashSet<_>>();
+
+    // once the garden repeats out past the start the plots reached every
+    // time the edge moves on a tile grow with the area, so the counts every
+    // `sq_size` steps settle into a quadratic; keep sampling until they have
+    let mut current = [*start].into_iter().collect::<HashSet<_>>();
     let mut coef = Vec::new();
-    for i in 0..=(base + sq_size * 2 + 1) {
+    for i in 0.. {
+        if i >= base && (i - base).is_multiple_of(sq_size) {
+            coef.push(current.len());
+            if coef.len() - 1 == reps {
+                return current.len().to_string();
+            }
+            if coef.len() >= SETTLED_SAMPLES {
+                let first = coef.len() - SETTLED_SAMPLES;
+                let fit = PolynomialSequence::new(&coef[first..]).expect("there are samples");
+                if fit.degree() <= 2 {
+                    let x = i64::try_from(reps - first).expect("steps fit in an i64");
+                    return fit
+                        .integer_at(x)
+                        .expect("a count is a whole number")
+                        .to_string();
+                }
+            }
+        }
         current = current
             .iter()
-            .flat_map(|loc| next_step(*loc, size, &boulders))
+            .flat_map(|loc| next_step(*loc, *size, rocks))
             .collect::<HashSet<_>>();
-
-        if i >= base - 1 && (i - base + 1) % sq_size == 0 {
-            //println!("{i} - {} - {}", (i - base) / sq_size, current.len());
-            coef.push(current.len());
-        }
     }
-
-    //TODO assuming this is fit with a quadratic
-    let a = (coef[2] - 2 * coef[1] + coef[0]) / 2;
-    let b = coef[1] - coef[0] - a;
-    let c = coef[0];
-
-    let total = a * reps.pow(2) + b * reps + c;
-
-    total.to_string()
-    // TODO this doesn't work for general case
-}
-
-fn with_xy(span: Span) -> SpanIVec2 {
-    let x = i32::try_from(span.get_column()).expect("overflow") - 1;
-    let y = i32::try_from(span.location_line()).expect("wrap around") - 1;
-    span.map_extra(|()| IVec2::new(x, y))
-}
-
-fn parse_input(input: Span) -> IResult<Span, (IVec2, IVec2, HashSet<IVec2>)> {
-    fold_many1(
-        terminated(
-            many1(alt((tag("S"), tag("."), tag("#"))).map(with_xy)),
-            alt((complete::line_ending, eof)),
-        ),
-        || (IVec2::splat(0), IVec2::splat(0), HashSet::new()),
-        |(mut start, mut size, mut set), row| {
-            for spot in row {
-                if spot.fragment() == &"S" {
-                    start = spot.extra;
-                }
-                if spot.fragment() == &"#" {
-                    set.insert(spot.extra);
-                }
-                size = size.max(spot.extra + 1);
-            }
-            (start, size, set)
-        },
-    )(input)
+    unreachable!("the loop only ends by returning")
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     use rstest::rstest;
 
@@ -122,7 +96,7 @@ mod test {
     #[case(1000, "668697")]
     #[case(5000, "16733044")]
     fn part2_works(#[case] steps: usize, #[case] expected: &str) {
-        let result = part2(INPUT, steps);
+        let result = part2(&parse(INPUT), steps);
         assert_eq!(result, expected.to_string());
     }
 }
@@ -1,5 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_22::parse;
 use day_22::part1;
 use day_22::part2;
 
@@ -12,8 +15,13 @@ fn main() {
     let _profiler = dhat::Profiler::new_heap();
 
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let stack = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&stack);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&stack);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
@@ -0,0 +1,15 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use crate::stack::Stack;
+
+/// day 22 stack of bricks of aoc 2023, already settled
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a line isn't two `x,y,z` corners split by `~`
+#[must_use]
+pub fn parse(input: &str) -> Stack {
+    Stack::parse(input).expect("AOC should have valid input")
+}
@@ -0,0 +1,308 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! Settles the falling bricks once against a height map and keeps who rests
+//! on whom as a DAG, so both parts are questions about that graph.
+
+use glam::UVec3;
+use nom::{
+    bytes::complete::tag, character::complete, multi::separated_list1, sequence::separated_pair,
+    IResult, Parser,
+};
+
+/// A brick as the two opposite corners of its cubes, `start` being the
+/// smallest on every axis.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Brick {
+    pub start: UVec3,
+    pub end: UVec3,
+}
+
+impl Brick {
+    #[must_use]
+    pub fn new(a: UVec3, b: UVec3) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// the x,y columns this brick covers
+    pub fn footprint(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (self.start.x..=self.end.x)
+            .flat_map(move |x| (self.start.y..=self.end.y).map(move |y| (x, y)))
+    }
+
+    /// the same brick with its bottom at `z`
+    #[must_use]
+    pub fn dropped_to(&self, z: u32) -> Self {
+        let offset = UVec3::Z * (self.start.z - z);
+        Self {
+            start: self.start - offset,
+            end: self.end - offset,
+        }
+    }
+}
+
+/// The bricks after they have all fallen, in the order they landed, along
+/// with which bricks each one rests on.
+#[derive(Debug, Clone)]
+pub struct Stack {
+    bricks: Vec<Brick>,
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// parses a snapshot and lets it settle
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let (_, bricks) = parse_input(input).ok()?;
+        Some(Self::settle(bricks))
+    }
+
+    /// Drops the bricks lowest first onto a height map holding the top z of
+    /// each column and the brick that top belongs to, noting every brick a
+    /// landing brick touches on the way.
+    #[must_use]
+    pub fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(|brick| brick.start.z);
+        let width = bricks.iter().map(|b| b.end.x + 1).max().unwrap_or(0) as usize;
+        let depth = bricks.iter().map(|b| b.end.y + 1).max().unwrap_or(0) as usize;
+        let mut height_map: Vec<(u32, Option<usize>)> = vec![(0, None); width * depth];
+        let mut settled = Vec::with_capacity(bricks.len());
+        let mut supports = vec![Vec::new(); bricks.len()];
+        let mut supported_by = vec![Vec::new(); bricks.len()];
+
+        for (id, brick) in bricks.iter().enumerate() {
+            let columns = brick
+                .footprint()
+                .map(|(x, y)| y as usize * width + x as usize)
+                .collect::<Vec<_>>();
+            let floor = columns
+                .iter()
+                .map(|&column| height_map[column].0)
+                .max()
+                .unwrap_or(0);
+            let mut below = columns
+                .iter()
+                .filter_map(|&column| match height_map[column] {
+                    (top, Some(other)) if top == floor => Some(other),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            below.sort_unstable();
+            below.dedup();
+            for &other in &below {
+                supports[other].push(id);
+            }
+            supported_by[id] = below;
+
+            let landed = brick.dropped_to(floor + 1);
+            for column in columns {
+                height_map[column] = (landed.end.z, Some(id));
+            }
+            settled.push(landed);
+        }
+
+        Self {
+            bricks: settled,
+            supports,
+            supported_by,
+        }
+    }
+
+    #[must_use]
+    pub fn bricks(&self) -> &[Brick] {
+        &self.bricks
+    }
+
+    /// the bricks resting directly on `id`
+    #[must_use]
+    pub fn supports(&self, id: usize) -> &[usize] {
+        &self.supports[id]
+    }
+
+    /// the bricks `id` rests directly on, empty when it is on the ground
+    #[must_use]
+    pub fn supported_by(&self, id: usize) -> &[usize] {
+        &self.supported_by[id]
+    }
+
+    /// the bricks that can go without anything else moving
+    pub fn safe_to_disintegrate(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.bricks.len()).filter(|&id| {
+            self.supports[id]
+                .iter()
+                .all(|&above| self.supported_by[above].len() > 1)
+        })
+    }
+
+    /// The immediate dominator of every brick with the ground as the root,
+    /// `None` meaning the ground. A brick falls with another exactly when
+    /// every path from the ground to it goes through that other brick.
+    ///
+    /// Bricks landed lowest first, so all of a brick's supports already
+    /// have their dominator when it is reached, and its own is where they
+    /// meet in the tree.
+    #[must_use]
+    pub fn dominators(&self) -> Vec<Option<usize>> {
+        let mut idom: Vec<Option<usize>> = Vec::with_capacity(self.bricks.len());
+        let mut depth: Vec<usize> = Vec::with_capacity(self.bricks.len());
+        let depth_of = |depth: &[usize], node: Option<usize>| node.map_or(0, |n| depth[n]);
+
+        for id in 0..self.bricks.len() {
+            let mut below = self.supported_by[id].iter().copied().map(Some);
+            let mut meet = below.next().flatten();
+            for mut other in below {
+                while meet != other {
+                    if depth_of(&depth, meet) >= depth_of(&depth, other) {
+                        meet = meet.and_then(|n| idom[n]);
+                    } else {
+                        other = other.and_then(|n| idom[n]);
+                    }
+                }
+            }
+            depth.push(depth_of(&depth, meet) + 1);
+            idom.push(meet);
+        }
+        idom
+    }
+
+    /// How many other bricks fall when each brick is disintegrated, which is
+    /// the size of the brick's subtree in the dominator tree.
+    #[must_use]
+    pub fn chain_reactions(&self) -> Vec<usize> {
+        let idom = self.dominators();
+        let mut falling = vec![0; self.bricks.len()];
+        for id in (0..self.bricks.len()).rev() {
+            if let Some(parent) = idom[id] {
+                falling[parent] += falling[id] + 1;
+            }
+        }
+        falling
+    }
+}
+
+fn parse_corner(input: &str) -> IResult<&str, UVec3> {
+    let (input, x) = complete::u32(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = complete::u32(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, z) = complete::u32(input)?;
+    Ok((input, UVec3::new(x, y, z)))
+}
+
+pub(crate) fn parse_input(input: &str) -> IResult<&str, Vec<Brick>> {
+    separated_list1(
+        complete::line_ending,
+        separated_pair(parse_corner, tag("~"), parse_corner).map(|(a, b)| Brick::new(a, b)),
+    )(input)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const INPUT: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    /// drops the bricks one at a time against every cube already down,
+    /// returning how many of them moved
+    fn drop_all(bricks: &[Brick]) -> (Vec<Brick>, usize) {
+        let mut sorted = bricks.to_vec();
+        sorted.sort_by_key(|brick| brick.start.z);
+        let mut settled: Vec<Brick> = Vec::new();
+        let mut moved = 0;
+        for brick in sorted {
+            let floor = settled
+                .iter()
+                .filter(|other| {
+                    other.footprint().any(|(x, y)| {
+                        (brick.start.x..=brick.end.x).contains(&x)
+                            && (brick.start.y..=brick.end.y).contains(&y)
+                    })
+                })
+                .map(|other| other.end.z)
+                .max()
+                .unwrap_or(0);
+            if floor + 1 != brick.start.z {
+                moved += 1;
+            }
+            settled.push(brick.dropped_to(floor + 1));
+        }
+        (settled, moved)
+    }
+
+    /// a pile of up to 60 random bricks, none more than three long, each in
+    /// its own layers so none of them start out overlapping
+    fn bricks() -> impl Strategy<Value = Vec<Brick>> {
+        prop::collection::vec((0_u32..5, 0_u32..5, 0_u32..3, 0_u32..3), 1..60).prop_map(|bricks| {
+            bricks
+                .into_iter()
+                .enumerate()
+                .map(|(i, (x, y, length, axis))| {
+                    let layer = u32::try_from(i).unwrap() * 3 + 1;
+                    let start = UVec3::new(x, y, layer);
+                    let end = match axis {
+                        0 => start + UVec3::X * length.min(4 - x),
+                        1 => start + UVec3::Y * length.min(4 - y),
+                        _ => start + UVec3::Z * length,
+                    };
+                    Brick::new(start, end)
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn settles_example() {
+        let stack = Stack::parse(INPUT).unwrap();
+        assert_eq!(
+            stack.bricks().iter().map(|b| b.start.z).collect::<Vec<_>>(),
+            vec![1, 2, 2, 3, 3, 4, 5]
+        );
+        assert_eq!(stack.supported_by(0), &[] as &[usize]);
+        assert_eq!(stack.supports(0), &[1, 2]);
+        assert_eq!(stack.supported_by(5), &[3, 4]);
+        assert_eq!(stack.safe_to_disintegrate().count(), 5);
+        assert_eq!(
+            stack.dominators(),
+            vec![None, Some(0), Some(0), Some(0), Some(0), Some(0), Some(5)]
+        );
+        assert_eq!(stack.chain_reactions(), vec![6, 0, 0, 0, 0, 1, 0]);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn matches_dropping_one_at_a_time(bricks in bricks()) {
+            let (dropped, _) = drop_all(&bricks);
+            let stack = Stack::settle(bricks);
+            let mut settled = stack.bricks().to_vec();
+            settled.sort_by_key(|b| (b.start.z, b.start.x, b.start.y));
+            let mut expected = dropped;
+            expected.sort_by_key(|b| (b.start.z, b.start.x, b.start.y));
+            prop_assert_eq!(settled, expected);
+
+            for (id, &reaction) in stack.chain_reactions().iter().enumerate() {
+                let mut rest = stack.bricks().to_vec();
+                rest.remove(id);
+                let (_, fell) = drop_all(&rest);
+                prop_assert_eq!(reaction, fell, "brick {}", id);
+                prop_assert_eq!(
+                    stack.safe_to_disintegrate().any(|safe| safe == id),
+                    fell == 0,
+                    "brick {}",
+                    id
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use glam::IVec2;
+
+#[derive(Debug, Copy, Clone)]
+pub enum PointType {
+    Any,
+    OnlyDown,
+    OnlyLeft,
+    OnlyRight,
+    OnlyUp,
+}
+
+/// every tile of the map that can be walked on, forest left out
+pub type Trails = HashMap<IVec2, PointType>;
+
+/// day 23 hiking trails of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when the map is too big for an `IVec2`
+#[must_use]
+pub fn parse(input: &str) -> Trails {
+    parse_input(input)
+}
+
+fn parse_input(input: &str) -> Trails {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars().enumerate().filter_map(move |(x, c)| {
+                let pos = IVec2::new(i32::try_from(x).unwrap(), i32::try_from(y).unwrap());
+                match c {
+                    '.' => Some((pos, PointType::Any)),
+                    '>' => Some((pos, PointType::OnlyRight)),
+                    'v' => Some((pos, PointType::OnlyDown)),
+                    '^' => Some((pos, PointType::OnlyUp)),
+                    '<' => Some((pos, PointType::OnlyLeft)),
+                    _ => None,
+                }
+            })
+        })
+        .collect()
+}
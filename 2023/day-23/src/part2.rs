@@ -5,25 +5,17 @@ use std::collections::HashMap;
 use glam::IVec2;
 use petgraph::{algo, prelude::*};
 
-#[derive(Debug, Copy, Clone)]
-enum PointType {
-    Any,
-    OnlyDown,
-    OnlyLeft,
-    OnlyRight,
-    OnlyUp,
-}
+use crate::model::{PointType, Trails};
 
 /// day 23 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - maze the walkable tiles of the map
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// panics when there is no way from the top row to the bottom one
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let maze = parse_input(input);
+pub fn part2(maze: &Trails) -> String {
     //get the start position (assuming there is only one)
     let start = *maze.keys().find(|pos| pos.y == 0).unwrap();
     let end = maze.keys().fold(IVec2::splat(0), |max, current| {
@@ -39,8 +31,8 @@ pub fn part2(input: &str) -> String {
         .map(|(pos, point_type)| (pos, maze_graph.add_node(point_type)))
         .collect::<HashMap<_, _>>();
 
-    maze.iter()
-        .flat_map(|(pos, _point_type)| {
+    maze.keys()
+        .flat_map(|pos| {
             [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
                 .iter()
                 .copied()
@@ -64,29 +56,10 @@ pub fn part2(input: &str) -> String {
     .to_string()
 }
 
-fn parse_input(input: &str) -> HashMap<IVec2, PointType> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.chars().enumerate().filter_map(move |(x, c)| {
-                let pos = IVec2::new(i32::try_from(x).unwrap(), i32::try_from(y).unwrap());
-                match c {
-                    '.' => Some((pos, PointType::Any)),
-                    '>' => Some((pos, PointType::OnlyRight)),
-                    'v' => Some((pos, PointType::OnlyDown)),
-                    '^' => Some((pos, PointType::OnlyUp)),
-                    '<' => Some((pos, PointType::OnlyLeft)),
-                    _ => None,
-                }
-            })
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "#.#####################
 #.......#########...###
@@ -114,7 +87,7 @@ mod test {
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "154".to_string());
     }
 }
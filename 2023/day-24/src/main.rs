@@ -1,12 +1,20 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_24::parse;
 use day_24::part1;
 use day_24::part2;
 
 fn main() {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input, 200_000_000_000_000.0, 400_000_000_000_000.0);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let stones = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&stones, 200_000_000_000_000.0, 400_000_000_000_000.0);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&stones);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
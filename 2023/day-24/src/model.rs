@@ -0,0 +1,51 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use glam::I64Vec3;
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult, Parser,
+};
+
+/// A hailstone's position at time zero and how far it moves each tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stones {
+    pub start: I64Vec3,
+    pub velocity: I64Vec3,
+}
+
+/// day 24 hailstones of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a line isn't a position and velocity of `x, y, z` split by `@`
+#[must_use]
+pub fn parse(input: &str) -> Vec<Stones> {
+    let (_, stones) = parse_input(input).expect("Aoc should have valid input");
+    stones
+}
+
+fn parse_tuple(input: &str) -> IResult<&str, I64Vec3> {
+    let (input, x) = complete::i64(input)?;
+    let (input, _) = tuple((tag(","), complete::space0))(input)?;
+    let (input, y) = complete::i64(input)?;
+    let (input, _) = tuple((tag(","), complete::space0))(input)?;
+    let (input, z) = complete::i64(input)?;
+    Ok((input, I64Vec3::new(x, y, z)))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Stones>> {
+    separated_list1(
+        complete::line_ending,
+        separated_pair(
+            parse_tuple,
+            tuple((complete::space0, tag("@"), complete::space0)),
+            parse_tuple,
+        )
+        .map(|(start, velocity)| Stones { start, velocity }),
+    )(input)
+}
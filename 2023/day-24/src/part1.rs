@@ -1,24 +1,15 @@
 #![warn(clippy::all, clippy::pedantic)]
 
 use glam::DVec3;
-use nom::{
-    bytes::complete::tag,
-    character::complete,
-    multi::separated_list1,
-    number,
-    sequence::{separated_pair, tuple},
-    IResult, Parser,
-};
 
 use itertools::Itertools;
 
-#[derive(Debug, Clone, Copy)]
-struct Stones {
-    pub start: DVec3,
-    pub velocity: DVec3,
-}
+use crate::model::Stones;
+
 impl Stones {
     fn cross(&self, other: &Self) -> Option<DVec3> {
+        let (a_start, a_velocity) = (self.start.as_dvec3(), self.velocity.as_dvec3());
+        let (b_start, b_velocity) = (other.start.as_dvec3(), other.velocity.as_dvec3());
         // x1 + v_x1 *t = x_n
         // t= (x_n - x_1)/v_x1
         // (y_n - y_1)/v_y1 = (x_n - x_1)/v_x1
@@ -27,17 +18,16 @@ impl Stones {
         // x_n * ((v_y1/v_x1) - (v_y2/v_x2)) = x_1 * (v_y1/v_x1) - x_2 * (v_y2/v_x2) + y_2 - y_1
         // x1 + v1 *t == x2 + v2*t
         // (x1-x2)/(v2-v1) = t
-        let slope1 = self.velocity.y / self.velocity.x;
-        let slope2 = other.velocity.y / other.velocity.x;
+        let slope1 = a_velocity.y / a_velocity.x;
+        let slope2 = b_velocity.y / b_velocity.x;
         let denom = slope1 - slope2;
-        let x =
-            (self.start.x * slope1 - other.start.x * slope2 + other.start.y - self.start.y) / denom;
-        let y = slope1 * (x - self.start.x) + self.start.y;
+        let x = (a_start.x * slope1 - b_start.x * slope2 + b_start.y - a_start.y) / denom;
+        let y = slope1 * (x - a_start.x) + a_start.y;
         if !x.is_finite() || !y.is_finite() {
             return None;
         }
-        let t1 = (x - self.start.x) / self.velocity.x;
-        let t2 = (x - other.start.x) / other.velocity.x;
+        let t1 = (x - a_start.x) / a_velocity.x;
+        let t2 = (x - b_start.x) / b_velocity.x;
         if t1 < 0.0 || t2 < 0.0 {
             return None;
         }
@@ -48,13 +38,11 @@ impl Stones {
 /// day 24 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
-///
-/// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// - stones the parsed hailstones
+/// - min the lowest x and y to count a crossing at
+/// - max the highest x and y to count a crossing at
 #[must_use]
-pub fn part1(input: &str, min: f64, max: f64) -> String {
-    let (_, stones) = parse_input(input).expect("Aoc should have valid input");
+pub fn part1(stones: &[Stones], min: f64, max: f64) -> String {
     stones
         .iter()
         .combinations(2)
@@ -72,30 +60,10 @@ pub fn part1(input: &str, min: f64, max: f64) -> String {
         .to_string()
 }
 
-fn parse_tuple(input: &str) -> IResult<&str, DVec3> {
-    let (input, x) = number::complete::double(input)?;
-    let (input, _) = tuple((tag(","), complete::space0))(input)?;
-    let (input, y) = number::complete::double(input)?;
-    let (input, _) = tuple((tag(","), complete::space0))(input)?;
-    let (input, z) = number::complete::double(input)?;
-    Ok((input, DVec3::new(x, y, z)))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Stones>> {
-    separated_list1(
-        complete::line_ending,
-        separated_pair(
-            parse_tuple,
-            tuple((complete::space0, tag("@"), complete::space0)),
-            parse_tuple,
-        )
-        .map(|(start, velocity)| Stones { start, velocity }),
-    )(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
@@ -105,7 +73,7 @@ mod test {
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT, 7.0, 27.0);
+        let result = part1(&parse(INPUT), 7.0, 27.0);
         assert_eq!(result, "2".to_string());
     }
 }
@@ -2,25 +2,13 @@
 #![allow(clippy::similar_names)]
 
 use glam::I64Vec3;
-use nom::{
-    bytes::complete::tag,
-    character::complete,
-    multi::separated_list1,
-    sequence::{separated_pair, tuple},
-    IResult, Parser,
-};
-
 use itertools::Itertools;
-
 use num::rational::Ratio;
 
+use crate::model::Stones;
+
 type Cord = Ratio<i128>;
 
-#[derive(Debug, Clone, Copy)]
-struct Stones {
-    pub start: I64Vec3,
-    pub velocity: I64Vec3,
-}
 impl Stones {
     fn cross_xy(&self, other: &Self) -> Option<(Cord, Cord)> {
         let pax = Cord::from(i128::from(self.start.x));
@@ -187,12 +175,13 @@ fn get_missing_stone(stone1: Stone, stone2: Stone, stone3: Stone) -> Stone {
         }
 
         //gausean elimination
-        for row in (i + 1)..6 {
-            let mult = equations[row][i];
+        let pivot = equations[i];
+        for equation in &mut equations[(i + 1)..] {
+            let mult = equation[i];
             if mult != Cord::default() {
-                equations[row][i] = Cord::default();
-                for col in (i + 1)..7 {
-                    equations[row][col] -= equations[i][col] * mult;
+                equation[i] = Cord::default();
+                for (cell, leader) in equation[(i + 1)..].iter_mut().zip(&pivot[(i + 1)..]) {
+                    *cell -= *leader * mult;
                 }
             }
         }
@@ -215,13 +204,12 @@ fn get_missing_stone(stone1: Stone, stone2: Stone, stone3: Stone) -> Stone {
 /// day 24 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - stones the parsed hailstones
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
+/// panics when there aren't enough hailstones to pin the rock down
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let (_, stones) = parse_input(input).expect("Aoc should have valid input");
+pub fn part2(stones: &[Stones]) -> String {
     let iteresting_stones = stones
         .iter()
         .combinations(2)
@@ -248,30 +236,10 @@ pub fn part2(input: &str) -> String {
     (position.0.to_integer() + position.1.to_integer() + position.2.to_integer()).to_string()
 }
 
-fn parse_tuple(input: &str) -> IResult<&str, I64Vec3> {
-    let (input, x) = complete::i64(input)?;
-    let (input, _) = tuple((tag(","), complete::space0))(input)?;
-    let (input, y) = complete::i64(input)?;
-    let (input, _) = tuple((tag(","), complete::space0))(input)?;
-    let (input, z) = complete::i64(input)?;
-    Ok((input, I64Vec3::new(x, y, z)))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Stones>> {
-    separated_list1(
-        complete::line_ending,
-        separated_pair(
-            parse_tuple,
-            tuple((complete::space0, tag("@"), complete::space0)),
-            parse_tuple,
-        )
-        .map(|(start, velocity)| Stones { start, velocity }),
-    )(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
@@ -281,7 +249,7 @@ mod test {
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "47".to_string());
     }
 }
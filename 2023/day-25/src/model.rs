@@ -0,0 +1,85 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+use petgraph::prelude::*;
+
+/// The wiring diagram as a graph, with a node for every component.
+///
+/// Components are added in name order so the graph comes out the same every
+/// time for the same input.
+///
+/// # Panics
+/// panics when it cannot parse the input
+#[must_use]
+pub fn parse(input: &str) -> UnGraph<&str, u32> {
+    let (_, initial_map) = parse_input(input).expect("AOC should have valid input");
+
+    let all_node_strings = initial_map
+        .iter()
+        .flat_map(|(key, nodes)| {
+            let mut nodes = nodes.clone();
+            nodes.push(key);
+            nodes
+        })
+        .unique()
+        .sorted()
+        .collect::<Vec<_>>();
+
+    let mut graph = UnGraph::<&str, u32>::default();
+    let node_map = all_node_strings
+        .iter()
+        .map(|&id| (id, graph.add_node(id)))
+        .collect::<HashMap<_, _>>();
+
+    for (&src_id, dest_nodes) in initial_map.iter().sorted() {
+        for &dest_id in dest_nodes {
+            graph.add_edge(node_map[src_id], node_map[dest_id], 1);
+        }
+    }
+    graph
+}
+
+fn parse_input(input: &str) -> IResult<&str, HashMap<&str, Vec<&str>>> {
+    let (input, nodes_as_array) = separated_list1(
+        complete::line_ending,
+        separated_pair(
+            complete::alpha1,
+            tuple((tag(":"), complete::space0)),
+            separated_list1(complete::space1, complete::alpha1),
+        ),
+    )(input)?;
+    Ok((input, nodes_as_array.into_iter().collect()))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_dot::ToDot;
+
+    use super::*;
+
+    #[test]
+    fn wiring_to_dot() {
+        let dot = parse("jqt: rhn xhk\nrhn: xhk").to_dot();
+        assert_eq!(
+            dot,
+            r#"graph {
+    "0" [label="jqt"];
+    "1" [label="rhn"];
+    "2" [label="xhk"];
+    "0" -- "1" [label="1"];
+    "0" -- "2" [label="1"];
+    "1" -- "2" [label="1"];
+}
+"#
+        );
+    }
+}
@@ -1,19 +1,22 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use petgraph::prelude::*;
+
 #[must_use]
-pub fn part2(_input: &str) -> String {
+pub fn part2(_graph: &UnGraph<&str, u32>) -> String {
     "Not Finished".to_string()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
-    const INPUT: &str = "";
+    const INPUT: &str = "jqt: rhn xhk\nrhn: xhk";
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "Not Finished".to_string());
     }
 }
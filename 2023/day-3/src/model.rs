@@ -0,0 +1,66 @@
+#![warn(clippy::all, clippy::pedantic)]
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+pub struct SerialNumber {
+    pub no: u64,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    pub serials: Vec<SerialNumber>,
+    pub symbols: BTreeMap<(usize, usize), char>,
+}
+
+/// the part numbers and symbols of the engine schematic
+#[must_use]
+pub fn parse(input: &str) -> Schematic {
+    let mut numbers = Vec::new();
+    let mut symbols = BTreeMap::new();
+    for (line_no, line) in input.lines().enumerate() {
+        let mut prev_char = None;
+        let mut cur_no = 0_u64;
+        let mut cur_no_row_start = 0_usize;
+        for (row_no, c) in line.chars().enumerate() {
+            if let Some(d) = c.to_digit(10) {
+                if prev_char.is_some() {
+                    cur_no = cur_no * 10 + u64::from(d);
+                } else {
+                    cur_no = u64::from(d);
+                    cur_no_row_start = row_no;
+                }
+                prev_char = Some(c);
+            } else {
+                if prev_char.is_some() {
+                    //handle saving number off
+                    numbers.push(SerialNumber {
+                        no: cur_no,
+                        start: (cur_no_row_start, line_no),
+                        end: (row_no - 1, line_no),
+                    });
+                }
+                prev_char = None;
+                if c == '.' {
+                    //move along space
+                    continue;
+                }
+                //store symbol
+                let _ = symbols.insert((row_no, line_no), c);
+            }
+        }
+        //need to account for new line numbers
+        if prev_char.is_some() {
+            numbers.push(SerialNumber {
+                no: cur_no,
+                start: (cur_no_row_start, line_no),
+                end: (line.len() - 1, line_no),
+            });
+        }
+    }
+    Schematic {
+        serials: numbers,
+        symbols,
+    }
+}
@@ -1,12 +1,6 @@
 #![warn(clippy::all, clippy::pedantic)]
-use std::collections::BTreeMap;
 
-#[derive(Debug)]
-struct SerialNumber {
-    pub no: u64,
-    pub start: (usize, usize),
-    pub end: (usize, usize),
-}
+use crate::model::{Schematic, SerialNumber};
 
 impl SerialNumber {
     fn generate_adjacent(&self) -> Vec<(usize, usize)> {
@@ -27,8 +21,8 @@ impl SerialNumber {
 }
 
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (serials, symbols) = parse_input(input);
+pub fn part1(schematic: &Schematic) -> String {
+    let Schematic { serials, symbols } = schematic;
     serials
         .iter()
         .filter(|x| {
@@ -41,55 +35,10 @@ pub fn part1(input: &str) -> String {
         .to_string()
 }
 
-fn parse_input(input: &str) -> (Vec<SerialNumber>, BTreeMap<(usize, usize), char>) {
-    let mut numbers = Vec::new();
-    let mut symbols = BTreeMap::new();
-    for (line_no, line) in input.lines().enumerate() {
-        let mut prev_char = None;
-        let mut cur_no = 0_u64;
-        let mut cur_no_row_start = 0_usize;
-        for (row_no, c) in line.chars().enumerate() {
-            if let Some(d) = c.to_digit(10) {
-                if prev_char.is_some() {
-                    cur_no = cur_no * 10 + u64::from(d);
-                } else {
-                    cur_no = u64::from(d);
-                    cur_no_row_start = row_no;
-                }
-                prev_char = Some(c);
-            } else {
-                if prev_char.is_some() {
-                    //handle saving number off
-                    numbers.push(SerialNumber {
-                        no: cur_no,
-                        start: (cur_no_row_start, line_no),
-                        end: (row_no - 1, line_no),
-                    });
-                }
-                prev_char = None;
-                if c == '.' {
-                    //move along space
-                    continue;
-                }
-                //store symbol
-                let _ = symbols.insert((row_no, line_no), c);
-            }
-        }
-        //need to account for new line numbers
-        if prev_char.is_some() {
-            numbers.push(SerialNumber {
-                no: cur_no,
-                start: (cur_no_row_start, line_no),
-                end: (line.len() - 1, line_no),
-            });
-        }
-    }
-    (numbers, symbols)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
 
     const INPUT: &str = "467..114..
 ...*......
@@ -117,13 +66,13 @@ mod test {
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT);
+        let result = part1(&parse(INPUT));
         assert_eq!(result, "4361".to_string());
     }
 
     #[test]
     fn part1_works_more() {
-        let result = part1(INPUT2);
+        let result = part1(&parse(INPUT2));
         assert_eq!(result, "925".to_string());
     }
 }
@@ -1,12 +1,30 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_4::parse;
 use day_4::part1;
 use day_4::part2;
 
-fn main() {
+use error_stack::{Result, ResultExt};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+enum Day4Error {
+    #[error("Part 2 failed")]
+    Part2Error,
+}
+
+fn main() -> Result<(), Day4Error> {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let cards = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&cards);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&cards).change_context(Day4Error::Part2Error)?;
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
+    Ok(())
 }
@@ -0,0 +1,74 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    combinator::map_res,
+    multi::{many1, separated_list1},
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+use crate::scratchcard::NumberSet;
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: usize,
+    pub game_numbers: NumberSet,
+    pub my_numbers: NumberSet,
+}
+
+impl Card {
+    #[must_use]
+    pub fn get_win_count(&self) -> usize {
+        self.my_numbers.matches(&self.game_numbers)
+    }
+}
+
+fn parse_num_list(input: &str) -> IResult<&str, NumberSet> {
+    many1(terminated(complete::u32, complete::space0))
+        .map(NumberSet::new)
+        .parse(input)
+}
+
+fn parse_numbers(input: &str) -> IResult<&str, (NumberSet, NumberSet)> {
+    separated_pair(
+        parse_num_list,
+        tuple((tag("|"), complete::space1)),
+        parse_num_list,
+    )(input)
+}
+
+pub(crate) fn parse_card(input: &str) -> IResult<&str, Card> {
+    let (input, (id, (my_numbers, game_numbers))) = separated_pair(
+        preceded(
+            tuple((tag("Card"), complete::space1)),
+            map_res(complete::u32, usize::try_from),
+        ),
+        tuple((tag(":"), complete::space1)),
+        parse_numbers,
+    )(input)?;
+
+    Ok((
+        input,
+        Card {
+            id,
+            game_numbers,
+            my_numbers,
+        },
+    ))
+}
+
+/// day 4 scratchcards of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a line isn't a `Card` id then the winning and held numbers split by `|`
+#[must_use]
+pub fn parse(input: &str) -> Vec<Card> {
+    let (_, cards) =
+        separated_list1(complete::line_ending, parse_card)(input).expect("there should be input");
+    cards
+}
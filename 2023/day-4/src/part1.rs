@@ -1,24 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{
-    bytes::complete::tag,
-    character::complete,
-    multi::{fold_many1, separated_list1},
-    sequence::{preceded, separated_pair, tuple},
-    IResult,
-};
-use std::collections::HashSet;
-
-struct Card {
-    pub _id: u8,
-    pub game_numbers: HashSet<u8>,
-    pub my_numbers: HashSet<u8>,
-}
+use crate::model::Card;
 
 impl Card {
-    fn get_win_count(&self) -> usize {
-        self.my_numbers.intersection(&self.game_numbers).count()
-    }
     fn get_score(&self) -> Option<usize> {
         let count = self.get_win_count();
         if count == 0 {
@@ -32,14 +16,12 @@ impl Card {
 /// day 4 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - cards the parsed scratchcards
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// panics when ever the number of game numbers is greater than usize
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (_, cards) = parse_input(input).expect("there should be input");
+pub fn part1(cards: &[Card]) -> String {
     cards
         .iter()
         .filter_map(Card::get_score)
@@ -47,49 +29,10 @@ pub fn part1(input: &str) -> String {
         .to_string()
 }
 
-fn parse_num_list(input: &str) -> IResult<&str, HashSet<u8>> {
-    fold_many1(
-        tuple((complete::u8, complete::space0)),
-        HashSet::new,
-        |mut acc, (x, _)| {
-            acc.insert(x);
-            acc
-        },
-    )(input)
-}
-
-fn parse_numbers(input: &str) -> IResult<&str, (HashSet<u8>, HashSet<u8>)> {
-    separated_pair(
-        parse_num_list,
-        tuple((tag("|"), complete::space1)),
-        parse_num_list,
-    )(input)
-}
-
-fn parse_card(input: &str) -> IResult<&str, Card> {
-    let (input, (id, (my_numbers, game_numbers))) = separated_pair(
-        preceded(tuple((tag("Card"), complete::space1)), complete::u8),
-        tuple((tag(":"), complete::space1)),
-        parse_numbers,
-    )(input)?;
-
-    Ok((
-        input,
-        Card {
-            _id: id,
-            game_numbers,
-            my_numbers,
-        },
-    ))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Card>> {
-    separated_list1(complete::line_ending, parse_card)(input)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::{parse, parse_card};
     use rstest::rstest;
 
     #[rstest]
@@ -114,7 +57,7 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT);
+        let result = part1(&parse(INPUT));
         assert_eq!(result, "13".to_string());
     }
 }
@@ -1,110 +1,41 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{
-    bytes::complete::tag,
-    character::complete,
-    combinator::map,
-    multi::{fold_many1, separated_list1},
-    sequence::{preceded, separated_pair, tuple},
-    IResult,
-};
-use std::collections::{BTreeMap, HashSet};
+use error_stack::{Report, Result, ResultExt};
+use thiserror::Error;
 
-struct Card {
-    pub id: usize,
-    pub game_numbers: HashSet<u8>,
-    pub my_numbers: HashSet<u8>,
-}
-impl Card {
-    fn get_win_count(&self) -> usize {
-        self.my_numbers.intersection(&self.game_numbers).count()
-    }
+use crate::model::Card;
+use crate::scratchcard::copies;
+
+#[derive(Debug, Error)]
+pub enum Day4Part2Error {
+    #[error("Day 4 cards don't make a valid table")]
+    InvalidTable,
+    #[error("Day 4 ends up with more cards than fit in a u64")]
+    TooManyCards,
 }
 
-/// day 4 part 1 of aoc 2023
+/// day 4 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - cards the parsed scratchcards
 ///
-/// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
-#[must_use]
-pub fn part2(input: &str) -> String {
-    let (_, cards) = parse_input(input).expect("there should be input");
-    let mut cards_had = BTreeMap::new();
-    for card in cards {
-        if let Some(x) = cards_had.get_mut(&card.id) {
-            *x += 1;
-        } else {
-            cards_had.insert(card.id, 1);
-        }
-        let next_id = card.id + 1;
-        let last_winner = card.id + card.get_win_count();
-        //println!("{} - {next_id} {last_winner}", card.id);
-        if last_winner < next_id {
-            continue;
-        }
-        let card_count = *cards_had.get(&card.id).expect("already should have cards");
-        for id in next_id..=last_winner {
-            if let Some(x) = cards_had.get_mut(&id) {
-                *x += card_count;
-            } else {
-                cards_had.insert(id, card_count);
-            }
-        }
-    }
-    //println!("{cards_had:#?}");
-
-    cards_had.values().sum::<usize>().to_string()
-}
-
-fn parse_num_list(input: &str) -> IResult<&str, HashSet<u8>> {
-    fold_many1(
-        tuple((complete::u8, complete::space0)),
-        HashSet::new,
-        |mut acc, (x, _)| {
-            acc.insert(x);
-            acc
-        },
-    )(input)
-}
-
-fn parse_numbers(input: &str) -> IResult<&str, (HashSet<u8>, HashSet<u8>)> {
-    separated_pair(
-        parse_num_list,
-        tuple((tag("|"), complete::space1)),
-        parse_num_list,
-    )(input)
-}
-
-fn parse_card(input: &str) -> IResult<&str, Card> {
-    let (input, (id, (my_numbers, game_numbers))) = separated_pair(
-        preceded(
-            tuple((tag("Card"), complete::space1)),
-            map(complete::u8, usize::from),
-        ),
-        tuple((tag(":"), complete::space1)),
-        parse_numbers,
-    )(input)?;
-
-    Ok((
-        input,
-        Card {
-            id,
-            game_numbers,
-            my_numbers,
-        },
-    ))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Card>> {
-    separated_list1(complete::line_ending, parse_card)(input)
+/// # Errors
+/// - `InvalidTable` when the cards are out of order or win copies of cards
+///   past the end of the table
+/// - `TooManyCards` when the total doesn't fit in a `u64`
+pub fn part2(cards: &[Card]) -> Result<String, Day4Part2Error> {
+    let total = copies(cards)
+        .change_context(Day4Part2Error::InvalidTable)?
+        .into_iter()
+        .try_fold(0_u64, u64::checked_add)
+        .ok_or_else(|| Report::new(Day4Part2Error::TooManyCards))?;
+    Ok(total.to_string())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
 
     const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -115,7 +46,16 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT)).unwrap();
         assert_eq!(result, "30".to_string());
     }
+
+    #[test]
+    fn part2_rejects_copies_past_the_end() {
+        let result = part2(&parse(&INPUT.replace("| 74 77", "| 31 77")));
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            Day4Part2Error::InvalidTable
+        ));
+    }
 }
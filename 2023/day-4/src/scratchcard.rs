@@ -0,0 +1,211 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! The numbers on a scratchcard and how the copies they win pile up.
+
+use std::cmp::Ordering;
+
+use error_stack::{Report, Result};
+use thiserror::Error;
+
+use crate::model::Card;
+
+/// numbers below this get a bit each
+const SMALL: u32 = u128::BITS;
+
+/// The numbers on one side of a card.
+///
+/// The puzzle's cards only have numbers under 100, so those are kept as one
+/// bit each and matching two sides is an `and` and a popcount. A side with
+/// anything bigger falls back to a sorted list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberSet {
+    Small(u128),
+    /// sorted, with no number in it twice
+    Large(Vec<u32>),
+}
+
+impl NumberSet {
+    #[must_use]
+    pub fn new(numbers: impl IntoIterator<Item = u32>) -> Self {
+        let mut numbers = numbers.into_iter().collect::<Vec<_>>();
+        if numbers.iter().all(|number| *number < SMALL) {
+            Self::Small(
+                numbers
+                    .iter()
+                    .fold(0, |bits, number| bits | 1_u128 << number),
+            )
+        } else {
+            numbers.sort_unstable();
+            numbers.dedup();
+            Self::Large(numbers)
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Small(bits) => bits.count_ones() as usize,
+            Self::Large(numbers) => numbers.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn contains(&self, number: u32) -> bool {
+        match self {
+            Self::Small(bits) => number < SMALL && bits & 1_u128 << number != 0,
+            Self::Large(numbers) => numbers.binary_search(&number).is_ok(),
+        }
+    }
+
+    /// how many numbers are in both sets
+    #[must_use]
+    pub fn matches(&self, other: &Self) -> usize {
+        match (self, other) {
+            (Self::Small(a), Self::Small(b)) => (a & b).count_ones() as usize,
+            (small @ Self::Small(_), Self::Large(large))
+            | (Self::Large(large), small @ Self::Small(_)) => large
+                .iter()
+                .filter(|number| small.contains(**number))
+                .count(),
+            (Self::Large(a), Self::Large(b)) => {
+                let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+                let mut count = 0;
+                while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+                    match x.cmp(y) {
+                        Ordering::Less => {
+                            a.next();
+                        }
+                        Ordering::Greater => {
+                            b.next();
+                        }
+                        Ordering::Equal => {
+                            count += 1;
+                            a.next();
+                            b.next();
+                        }
+                    }
+                }
+                count
+            }
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CopiesError {
+    #[error("card {found} is where card {expected} should be")]
+    OutOfOrder { expected: usize, found: usize },
+    #[error("card {card} wins copies of the next {wins} cards but only {left} come after it")]
+    PastTheEnd {
+        card: usize,
+        wins: usize,
+        left: usize,
+    },
+    #[error("more copies of card {0} than fit in a u64")]
+    TooManyCopies(usize),
+}
+
+/// How many of each card there are once all the copies are won, the
+/// original included, in the order of the table.
+///
+/// Every card adds its count to a running total for the cards it wins and
+/// takes it back off after the last one, so the table is a single pass
+/// however many copies pile up.
+///
+/// # Errors
+/// - `OutOfOrder` when the cards aren't numbered one after another
+/// - `PastTheEnd` when a card wins copies of cards the table doesn't have
+/// - `TooManyCopies` when a card's count doesn't fit in a `u64`
+pub fn copies(cards: &[Card]) -> Result<Vec<u64>, CopiesError> {
+    let first = cards.first().map_or(0, |card| card.id);
+    // what to take back off the running total when reaching each card
+    let mut expiring = vec![0_u64; cards.len() + 1];
+    let mut won = 0_u64;
+    let mut counts = Vec::with_capacity(cards.len());
+    for (position, card) in cards.iter().enumerate() {
+        if card.id != first + position {
+            return Err(Report::new(CopiesError::OutOfOrder {
+                expected: first + position,
+                found: card.id,
+            }));
+        }
+        won -= expiring[position];
+        let count = won
+            .checked_add(1)
+            .ok_or_else(|| Report::new(CopiesError::TooManyCopies(card.id)))?;
+        counts.push(count);
+
+        let wins = card.get_win_count();
+        let left = cards.len() - position - 1;
+        if wins > left {
+            return Err(Report::new(CopiesError::PastTheEnd {
+                card: card.id,
+                wins,
+                left,
+            }));
+        }
+        if wins > 0 {
+            won = won
+                .checked_add(count)
+                .ok_or_else(|| Report::new(CopiesError::TooManyCopies(card.id + 1)))?;
+            expiring[position + 1 + wins] += count;
+        }
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::parse;
+
+    const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn sets_match_either_way() {
+        let small = NumberSet::new([3, 17, 127, 17]);
+        let large = NumberSet::new([17, 128, 3, 70_000, 3]);
+        assert_eq!(small, NumberSet::Small(1 << 3 | 1 << 17 | 1 << 127));
+        assert_eq!(large, NumberSet::Large(vec![3, 17, 128, 70_000]));
+        assert_eq!((small.len(), large.len()), (3, 4));
+        assert!(large.contains(70_000) && !small.contains(128));
+        assert_eq!(small.matches(&large), 2);
+        assert_eq!(large.matches(&small), 2);
+        assert_eq!(large.matches(&NumberSet::new([128, 70_000, 5_000_000])), 2);
+        assert!(NumberSet::new([]).is_empty());
+    }
+
+    #[test]
+    fn counts_copies() {
+        assert_eq!(copies(&parse(INPUT)).unwrap(), vec![1, 2, 4, 8, 14, 1]);
+        let cards = parse("Card 7: 300 1000 | 1000 300 4\nCard 8: 5 | 6\nCard 9: 5 | 6");
+        assert_eq!(copies(&cards).unwrap(), vec![1, 2, 2]);
+    }
+
+    #[test]
+    fn rejects_bad_tables() {
+        let error = |input: &str| {
+            copies(&parse(input))
+                .unwrap_err()
+                .current_context()
+                .to_string()
+        };
+        assert_eq!(
+            error("Card 1: 1 2 | 2 1\nCard 2: 3 | 4"),
+            "card 1 wins copies of the next 2 cards but only 1 come after it"
+        );
+        assert_eq!(
+            error("Card 1: 1 | 2\nCard 3: 3 | 4"),
+            "card 3 is where card 2 should be"
+        );
+    }
+}
@@ -0,0 +1,399 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! The almanac's maps as piecewise shifts of whole ranges, so the chain of
+//! them can be folded into the one seed to location map.
+
+use core::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTypeError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl FromStr for Type {
+    type Err = ParseTypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "seed" => Ok(Self::Seed),
+            "soil" => Ok(Self::Soil),
+            "fertilizer" => Ok(Self::Fertilizer),
+            "water" => Ok(Self::Water),
+            "light" => Ok(Self::Light),
+            "temperature" => Ok(Self::Temperature),
+            "humidity" => Ok(Self::Humidity),
+            "location" => Ok(Self::Location),
+            _ => Err(ParseTypeError),
+        }
+    }
+}
+
+/// One stretch of a [`PiecewiseMap`], sending `from` to the same length
+/// starting at `to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    pub from: Range<u64>,
+    pub to: u64,
+}
+
+impl Piece {
+    /// where the piece lands, cut short at `u64::MAX` like the domain is
+    fn image(&self) -> Range<u64> {
+        self.to..self.to.saturating_add(self.from.end - self.from.start)
+    }
+}
+
+/// A map of every number in `0..u64::MAX` that shifts whole ranges,
+/// numbers outside any given range mapping to themselves.
+///
+/// The pieces are kept sorted, touching and covering the whole domain, so
+/// every lookup and combination is a walk or a binary search over them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl Default for PiecewiseMap {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl PiecewiseMap {
+    #[must_use]
+    pub fn identity() -> Self {
+        Self {
+            pieces: vec![Piece {
+                from: 0..u64::MAX,
+                to: 0,
+            }],
+        }
+    }
+
+    /// Builds the map from almanac lines of `(destination, source, length)`,
+    /// the first line covering a number winning if they overlap.
+    #[must_use]
+    pub fn from_entries(entries: impl IntoIterator<Item = (u64, u64, u64)>) -> Self {
+        let mut pieces: Vec<Piece> = Vec::new();
+        for (to, from, count) in entries {
+            let end = from.saturating_add(count);
+            // keep only what earlier lines haven't claimed
+            let mut start = from;
+            let mut claimed = pieces
+                .iter()
+                .map(|piece| piece.from.clone())
+                .filter(|range| range.start < end && start < range.end)
+                .collect::<Vec<_>>();
+            claimed.sort_by_key(|range| range.start);
+            let mut fresh = Vec::new();
+            for range in claimed {
+                if start < range.start {
+                    fresh.push(start..range.start);
+                }
+                start = start.max(range.end);
+            }
+            if start < end {
+                fresh.push(start..end);
+            }
+            pieces.extend(fresh.into_iter().map(|range| Piece {
+                to: to.saturating_add(range.start - from),
+                from: range,
+            }));
+        }
+        Self::from_pieces(pieces)
+    }
+
+    /// sorts the given disjoint pieces, fills the gaps with the identity and
+    /// joins up neighbours that carry on from each other
+    fn from_pieces(mut given: Vec<Piece>) -> Self {
+        given.sort_by_key(|piece| piece.from.start);
+        let mut pieces: Vec<Piece> = Vec::with_capacity(given.len() * 2 + 1);
+        let mut push = |piece: Piece| {
+            if piece.from.is_empty() {
+                return;
+            }
+            match pieces.last_mut() {
+                Some(last) if last.image().end == piece.to => last.from.end = piece.from.end,
+                _ => pieces.push(piece),
+            }
+        };
+        let mut next = 0;
+        for piece in given {
+            push(Piece {
+                from: next..piece.from.start,
+                to: next,
+            });
+            next = piece.from.end;
+            push(piece);
+        }
+        push(Piece {
+            from: next..u64::MAX,
+            to: next,
+        });
+        Self { pieces }
+    }
+
+    #[must_use]
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// the piece holding `x`
+    fn piece(&self, x: u64) -> &Piece {
+        let index = self.pieces.partition_point(|piece| piece.from.end <= x);
+        &self.pieces[index.min(self.pieces.len() - 1)]
+    }
+
+    #[must_use]
+    pub fn apply(&self, x: u64) -> u64 {
+        let piece = self.piece(x);
+        piece.to.saturating_add(x - piece.from.start)
+    }
+
+    /// The map doing `self` and then `then`, split wherever either of them
+    /// is.
+    #[must_use]
+    pub fn then(&self, then: &Self) -> Self {
+        let mut pieces = Vec::new();
+        for piece in &self.pieces {
+            let image = piece.image();
+            let first = then.pieces.partition_point(|p| p.from.end <= image.start);
+            for next in then.pieces[first..]
+                .iter()
+                .take_while(|p| p.from.start < image.end)
+            {
+                let start = image.start.max(next.from.start);
+                let end = image.end.min(next.from.end);
+                pieces.push(Piece {
+                    from: piece.from.start + (start - image.start)
+                        ..piece.from.start + (end - image.start),
+                    to: next.to.saturating_add(start - next.from.start),
+                });
+            }
+        }
+        Self::from_pieces(pieces)
+    }
+
+    /// The map taking every output back to its input, `None` when two
+    /// numbers land on the same place or some number is never reached.
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let mut pieces = self
+            .pieces
+            .iter()
+            .map(|piece| Piece {
+                from: piece.image(),
+                to: piece.from.start,
+            })
+            .collect::<Vec<_>>();
+        pieces.sort_by_key(|piece| piece.from.start);
+        let mut next = 0;
+        for piece in &pieces {
+            if piece.from.start != next {
+                return None;
+            }
+            next = piece.from.end;
+        }
+        (next == u64::MAX).then(|| Self::from_pieces(pieces))
+    }
+
+    /// Where the numbers in `range` end up, as sorted, disjoint ranges.
+    #[must_use]
+    pub fn image(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let first = self.pieces.partition_point(|p| p.from.end <= range.start);
+        let mut images = self.pieces[first..]
+            .iter()
+            .take_while(|p| p.from.start < range.end)
+            .map(|piece| {
+                let start = range.start.max(piece.from.start);
+                let end = range.end.min(piece.from.end);
+                piece.to.saturating_add(start - piece.from.start)
+                    ..piece.to.saturating_add(end - piece.from.start)
+            })
+            .collect::<Vec<_>>();
+        images.sort_by_key(|image| image.start);
+        let mut merged: Vec<Range<u64>> = Vec::with_capacity(images.len());
+        for image in images {
+            match merged.last_mut() {
+                Some(last) if image.start <= last.end => last.end = last.end.max(image.end),
+                _ => merged.push(image),
+            }
+        }
+        merged
+    }
+}
+
+/// The seeds to plant and the maps from one type to the next.
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<(Type, Type, PiecewiseMap)>,
+}
+
+impl Almanac {
+    /// every map along the way from seed to location composed together,
+    /// `None` if the chain doesn't reach a location
+    #[must_use]
+    pub fn seed_to_location(&self) -> Option<PiecewiseMap> {
+        let mut map = PiecewiseMap::identity();
+        let mut from_type = Type::Seed;
+        for _ in 0..self.maps.len() {
+            if from_type == Type::Location {
+                break;
+            }
+            let (_, to_type, next) = self.maps.iter().find(|(from, _, _)| *from == from_type)?;
+            map = map.then(next);
+            from_type = *to_type;
+        }
+        (from_type == Type::Location).then_some(map)
+    }
+
+    /// the seed line read as pairs of start and length, cut short at
+    /// `u64::MAX` and leaving out any with no seeds in them
+    #[must_use]
+    pub fn seed_ranges(&self) -> Vec<Range<u64>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0].saturating_add(pair[1]))
+            .filter(|range| !range.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::parse;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    /// the lookup the almanac describes, one line at a time
+    fn by_hand(entries: &[(u64, u64, u64)], x: u64) -> u64 {
+        entries
+            .iter()
+            .find(|(_, from, count)| (*from..from + count).contains(&x))
+            .map_or(x, |(to, from, _)| to + (x - from))
+    }
+
+    #[test]
+    fn applies_entries() {
+        let entries = [(50, 98, 2), (52, 50, 48)];
+        let map = PiecewiseMap::from_entries(entries);
+        for x in 0..120 {
+            assert_eq!(map.apply(x), by_hand(&entries, x), "{x}");
+        }
+        assert_eq!(map.apply(u64::MAX - 1), u64::MAX - 1);
+        assert_eq!(map.pieces().len(), 4);
+    }
+
+    #[test]
+    fn composes_the_chain() {
+        let almanac = parse(INPUT);
+        let map = almanac.seed_to_location().unwrap();
+        for x in 0..200 {
+            let step_by_step = almanac.maps.iter().fold(x, |x, (_, _, map)| map.apply(x));
+            assert_eq!(map.apply(x), step_by_step, "{x}");
+        }
+        assert_eq!(
+            almanac
+                .seeds
+                .iter()
+                .map(|&seed| map.apply(seed))
+                .collect::<Vec<_>>(),
+            vec![82, 43, 86, 35]
+        );
+    }
+
+    #[test]
+    fn inverts() {
+        let almanac = parse(INPUT);
+        let map = almanac.seed_to_location().unwrap();
+        let inverse = map.inverse().unwrap();
+        for x in 0..200 {
+            assert_eq!(inverse.apply(map.apply(x)), x, "{x}");
+        }
+        assert_eq!(inverse.inverse().unwrap(), map);
+        assert_eq!(inverse.apply(46), 82);
+
+        let squashed = PiecewiseMap::from_entries([(0, 10, 5)]);
+        assert_eq!(squashed.inverse(), None);
+    }
+
+    #[test]
+    fn saturates_near_the_top() {
+        let map = PiecewiseMap::from_entries([(u64::MAX - 2, 0, 5)]);
+        assert_eq!(map.apply(1), u64::MAX - 1);
+        assert_eq!(map.apply(4), u64::MAX);
+        assert_eq!(map.image(0..5), vec![u64::MAX - 2..u64::MAX]);
+        assert_eq!(map.then(&map).apply(0), u64::MAX - 2);
+        assert_eq!(map.inverse(), None);
+    }
+
+    #[test]
+    fn seed_ranges_are_clamped_and_not_empty() {
+        let almanac = Almanac {
+            seeds: vec![5, 0, u64::MAX - 1, 10, 3, 2],
+            ..parse(INPUT)
+        };
+        assert_eq!(almanac.seed_ranges(), vec![u64::MAX - 1..u64::MAX, 3..5]);
+    }
+
+    #[test]
+    fn images_ranges() {
+        let almanac = parse(INPUT);
+        let map = almanac.seed_to_location().unwrap();
+        for start in (0..120).step_by(7) {
+            for length in [1, 5, 30, 80] {
+                let image = map.image(start..start + length);
+                let mut expected = (start..start + length)
+                    .map(|x| map.apply(x))
+                    .collect::<Vec<_>>();
+                expected.sort_unstable();
+                let flat = image.iter().flat_map(Clone::clone).collect::<Vec<_>>();
+                assert_eq!(flat, expected, "{start}+{length}");
+                assert!(image.windows(2).all(|w| w[0].end < w[1].start));
+            }
+        }
+    }
+}
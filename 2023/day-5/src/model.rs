@@ -0,0 +1,71 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    combinator::opt,
+    multi::separated_list1,
+    sequence::{separated_pair, terminated, tuple},
+    IResult,
+};
+
+use crate::almanac::{Almanac, PiecewiseMap, Type};
+
+/// the seeds and maps of day 5 of AOC 2023
+///
+/// # Arguments
+/// - input the puszzle input
+///
+/// # Panics
+/// panics whenever the input isn't parsable
+#[must_use]
+pub fn parse(input: &str) -> Almanac {
+    let (_, almanac) = parse_input(input).expect("aoc always has input");
+    almanac
+}
+
+fn parse_item_map_entry(input: &str) -> IResult<&str, (u64, u64, u64)> {
+    let (input, to) = complete::u64(input)?;
+    let (input, _) = complete::space1(input)?;
+    let (input, from) = complete::u64(input)?;
+    let (input, _) = complete::space1(input)?;
+    let (input, count) = complete::u64(input)?;
+    Ok((input, (to, from, count)))
+}
+
+fn parse_to_from(input: &str) -> IResult<&str, (Type, Type)> {
+    let (input, (to_type, from_type)) =
+        separated_pair(complete::alpha1, tag("-to-"), complete::alpha1)(input)?;
+    Ok((
+        input,
+        (
+            to_type.parse().expect("there will be a to type"),
+            from_type.parse().expect("there will be a from type"),
+        ),
+    ))
+}
+
+fn parse_map(input: &str) -> IResult<&str, (Type, Type, PiecewiseMap)> {
+    let (input, (from_type, to_type)) =
+        terminated(parse_to_from, tuple((complete::space1, tag("map:"))))(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, mapping) = separated_list1(complete::line_ending, parse_item_map_entry)(input)?;
+    let (input, _) = opt(complete::line_ending)(input)?;
+    Ok((
+        input,
+        (from_type, to_type, PiecewiseMap::from_entries(mapping)),
+    ))
+}
+
+fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
+    let (input, _) = tag("seeds:")(input)?;
+    let (input, _) = complete::space1(input)?;
+    separated_list1(complete::space1, complete::u64)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Almanac> {
+    let (input, seeds) = terminated(parse_seeds, complete::line_ending)(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, maps) = separated_list1(complete::line_ending, parse_map)(input)?;
+    Ok((input, Almanac { seeds, maps }))
+}
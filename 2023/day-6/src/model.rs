@@ -0,0 +1,74 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    multi::separated_list1,
+    sequence::{pair, preceded},
+    IResult,
+};
+
+/// The times and record distances as the sheet of paper lists them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sheet {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+}
+
+impl Sheet {
+    /// each race's time and record distance
+    #[must_use]
+    pub fn races(&self) -> Vec<(u64, u64)> {
+        self.times
+            .iter()
+            .interleave(self.distances.iter())
+            .copied()
+            .tuples()
+            .collect()
+    }
+
+    /// the one race you get reading the sheet without the spaces
+    ///
+    /// # Panics
+    /// panics when the numbers run together are too big
+    #[must_use]
+    pub fn kerned(&self) -> (u64, u64) {
+        let join = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(ToString::to_string)
+                .join("")
+                .parse::<u64>()
+                .expect("is a number")
+        };
+        (join(&self.times), join(&self.distances))
+    }
+}
+
+/// the race sheet of day 6 of AOC 2023
+///
+/// # Arguments
+/// - input the puszzle input
+///
+/// # Panics
+/// panics whenever the input isn't parsable
+#[must_use]
+pub fn parse(input: &str) -> Sheet {
+    let (_, sheet) = parse_input(input).expect("input expected");
+    sheet
+}
+
+fn parse_input(input: &str) -> IResult<&str, Sheet> {
+    let (input, times) = preceded(
+        pair(tag("Time:"), complete::space1),
+        separated_list1(complete::space1, complete::u64),
+    )(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, distances) = preceded(
+        pair(tag("Distance:"), complete::space1),
+        separated_list1(complete::space1, complete::u64),
+    )(input)?;
+
+    Ok((input, Sheet { times, distances }))
+}
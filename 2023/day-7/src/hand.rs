@@ -0,0 +1,220 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! Camel cards hands and the rules they are scored by, both parts only
+//! differ in the [`Rules`] they hand to the same evaluation.
+
+use std::cmp::Ordering;
+
+/// every card label there is, weakest first under the normal rules
+pub const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// the group sizes, largest first, that a hand needs to be at least that type
+pub const STANDARD_CATEGORIES: [(HandType, &[u8]); 7] = [
+    (HandType::HighCard, &[]),
+    (HandType::OnePair, &[2]),
+    (HandType::TwoPair, &[2, 2]),
+    (HandType::ThreeOfAKind, &[3]),
+    (HandType::FullHouse, &[3, 2]),
+    (HandType::FourOfAKind, &[4]),
+    (HandType::FiveOfAKind, &[5]),
+];
+
+/// How hands get ranked: the strength of each card, which card if any
+/// stands in for whatever helps most, and the categories hands fall in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// card labels weakest first
+    pub order: [char; 13],
+    pub wild: Option<char>,
+    /// categories weakest first, each with the group sizes it needs
+    pub categories: &'static [(HandType, &'static [u8])],
+}
+
+/// part 1, jacks are just jacks
+pub const STANDARD: Rules = Rules {
+    order: CARDS,
+    wild: None,
+    categories: &STANDARD_CATEGORIES,
+};
+
+/// part 2, jokers are the weakest card but count as anything
+pub const JOKERS: Rules = Rules {
+    order: [
+        'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+    ],
+    wild: Some('J'),
+    categories: &STANDARD_CATEGORIES,
+};
+
+impl Rules {
+    /// how strong a card is on its own, 0 being the weakest
+    ///
+    /// # Panics
+    /// when the card isn't one of [`CARDS`]
+    #[must_use]
+    pub fn strength(&self, card: char) -> u8 {
+        let index = self
+            .order
+            .iter()
+            .position(|&c| c == card)
+            .expect("cards are checked when parsed");
+        u8::try_from(index).expect("there are 13 cards")
+    }
+
+    /// The best category the cards make, with any wild cards joining the
+    /// largest group as that is never worse than anywhere else.
+    #[must_use]
+    pub fn classify(&self, cards: &[char; 5]) -> HandType {
+        let mut counts = [0_u8; 13];
+        let mut wild = 0;
+        for &card in cards {
+            if Some(card) == self.wild {
+                wild += 1;
+            } else {
+                counts[usize::from(self.strength(card))] += 1;
+            }
+        }
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts[0] += wild;
+
+        self.categories
+            .iter()
+            .rev()
+            .find(|(_, groups)| groups.iter().zip(counts).all(|(need, have)| have >= *need))
+            .map_or(HandType::HighCard, |(hand_type, _)| *hand_type)
+    }
+
+    /// orders hands by category and then card by card
+    #[must_use]
+    pub fn compare(&self, a: &Hand, b: &Hand) -> Ordering {
+        self.classify(&a.cards)
+            .cmp(&self.classify(&b.cards))
+            .then_with(|| {
+                a.cards
+                    .map(|card| self.strength(card))
+                    .cmp(&b.cards.map(|card| self.strength(card)))
+            })
+    }
+
+    /// the total of each bet times its hand's rank
+    #[must_use]
+    pub fn winnings(&self, hands: &[Hand]) -> usize {
+        let mut ranked = hands.iter().collect::<Vec<_>>();
+        ranked.sort_by(|a, b| self.compare(a, b));
+        ranked
+            .into_iter()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.bet as usize)
+            .sum()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Hand {
+    pub cards: [char; 5],
+    pub bet: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::parse_hand;
+
+    /// every multiset of 5 cards, each once
+    fn multisets() -> Vec<[char; 5]> {
+        let mut all = Vec::new();
+        for a in 0..13 {
+            for b in a..13 {
+                for c in b..13 {
+                    for d in c..13 {
+                        for e in d..13 {
+                            all.push([a, b, c, d, e].map(|i| CARDS[i]));
+                        }
+                    }
+                }
+            }
+        }
+        all
+    }
+
+    /// names the hand from how many different cards there are and the
+    /// biggest group, without any wild cards
+    fn plain_type(cards: &[char; 5]) -> HandType {
+        let mut distinct = cards.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        let most = distinct
+            .iter()
+            .map(|d| cards.iter().filter(|c| *c == d).count())
+            .max()
+            .unwrap();
+        match (distinct.len(), most) {
+            (1, _) => HandType::FiveOfAKind,
+            (2, 4) => HandType::FourOfAKind,
+            (2, _) => HandType::FullHouse,
+            (3, 3) => HandType::ThreeOfAKind,
+            (3, _) => HandType::TwoPair,
+            (4, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// tries every card in place of every joker
+    fn best_with_jokers(cards: [char; 5]) -> HandType {
+        match cards.iter().position(|&c| c == 'J') {
+            None => plain_type(&cards),
+            Some(i) => CARDS
+                .iter()
+                .filter(|&&c| c != 'J')
+                .map(|&c| {
+                    let mut swapped = cards;
+                    swapped[i] = c;
+                    best_with_jokers(swapped)
+                })
+                .max()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn classifies_every_multiset() {
+        let all = multisets();
+        assert_eq!(all.len(), 6188);
+        for cards in all {
+            assert_eq!(STANDARD.classify(&cards), plain_type(&cards), "{cards:?}");
+            assert_eq!(
+                JOKERS.classify(&cards),
+                best_with_jokers(cards),
+                "{cards:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn jokers_change_the_order() {
+        let (_, a) = parse_hand("KKKJ2 9").unwrap();
+        let (_, b) = parse_hand("QQQQ2 8").unwrap();
+        assert_eq!(STANDARD.compare(&a, &b), Ordering::Less);
+        assert_eq!(JOKERS.compare(&a, &b), Ordering::Greater);
+        let (_, c) = parse_hand("JKKK2 7").unwrap();
+        assert_eq!(JOKERS.compare(&c, &b), Ordering::Less);
+    }
+
+    #[test]
+    fn rejects_unknown_cards() {
+        assert!(parse_hand("QQQQ1 8").is_err());
+        assert!(parse_hand("QQQQ 8").is_err());
+    }
+}
@@ -1,9 +1,12 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
+use day_7::parse;
 use day_7::part1;
 use day_7::part2;
 
@@ -12,8 +15,13 @@ fn main() {
     let _profiler = dhat::Profiler::new_heap();
 
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let hands = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&hands);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&hands);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
@@ -0,0 +1,40 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{
+    character::complete, combinator::map_opt, multi::separated_list1, sequence::separated_pair,
+    IResult,
+};
+
+use crate::hand::{Hand, CARDS};
+
+/// the hands and bets of day 7 of AOC 2023
+///
+/// # Arguments
+/// - input the puszzle input
+///
+/// # Panics
+/// panics whenever the input isn't parsable
+#[must_use]
+pub fn parse(input: &str) -> Vec<Hand> {
+    let (_, hands) = parse_input(input).expect("always valid input");
+    hands
+}
+
+fn parse_cards(input: &str) -> IResult<&str, [char; 5]> {
+    map_opt(complete::alphanumeric1, |cards: &str| {
+        if !cards.chars().all(|c| CARDS.contains(&c)) {
+            return None;
+        }
+        cards.chars().collect::<Vec<_>>().try_into().ok()
+    })(input)
+}
+
+pub(crate) fn parse_hand(input: &str) -> IResult<&str, Hand> {
+    let (input, (cards, bet)) =
+        separated_pair(parse_cards, complete::space1, complete::u32)(input)?;
+    Ok((input, Hand { cards, bet }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Hand>> {
+    separated_list1(complete::line_ending, parse_hand)(input)
+}
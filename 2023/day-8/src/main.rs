@@ -1,12 +1,27 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use aoc_dot::ToDot;
+use day_8::parse;
 use day_8::part1;
 use day_8::part2;
 
 fn main() {
     let input = include_str!("./input.txt");
-    let part1_result = part1(input);
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input);
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let network = parse(input);
+    println!("parsed in {:?}", start.elapsed());
+    if let Some(path) = aoc_dot::path_from_args() {
+        network
+            .nodes
+            .write_dot(path)
+            .expect("dot file to be writable");
+    }
+    let start = Instant::now();
+    let part1_result = part1(&network);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&network);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
 }
@@ -0,0 +1,162 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use aoc_dot::{Edge, Node, ToDot};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, separated_pair, tuple},
+    IResult, Parser,
+};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Branches {
+    pub left: String,
+    pub right: String,
+}
+
+impl Branches {
+    #[must_use]
+    pub fn choose(&self, direction: Direction) -> &str {
+        match direction {
+            Direction::Left => &self.left,
+            Direction::Right => &self.right,
+        }
+    }
+}
+
+/// Where each node branches to, as parsed from the input.
+#[derive(Debug, Clone)]
+pub struct NodeTree(pub BTreeMap<String, Branches>);
+
+/// The directions to follow and the nodes to follow them through.
+#[derive(Debug, Clone)]
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: NodeTree,
+}
+
+/// Starting nodes are green and ending nodes red, with every node's left and
+/// right branch drawn as an edge.
+impl ToDot for NodeTree {
+    fn dot_nodes(&self) -> Vec<Node> {
+        self.0
+            .keys()
+            .map(|label| match label.chars().last() {
+                Some('A') => Node::new(label).attribute("color", "green"),
+                Some('Z') => Node::new(label).attribute("color", "red"),
+                _ => Node::new(label),
+            })
+            .collect()
+    }
+
+    fn dot_edges(&self) -> Vec<Edge> {
+        self.0
+            .iter()
+            .flat_map(|(label, branches)| {
+                [
+                    Edge::new(label, &branches.left).label("L"),
+                    Edge::new(label, &branches.right).label("R"),
+                ]
+            })
+            .collect()
+    }
+}
+
+/// day 8 network of aoc 2023
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when the input isn't a line of `L`/`R` directions then a blank line and the
+/// `AAA = (BBB, CCC)` nodes
+#[must_use]
+pub fn parse(input: &str) -> Network {
+    let (_, network) = parse_input(input).expect("aoc expects valid input");
+    network
+}
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, directions) = many1(alt((
+        tag("L").map(|_| Direction::Left),
+        tag("R").map(|_| Direction::Right),
+    )))(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    Ok((input, directions))
+}
+
+fn parse_branches(input: &str) -> IResult<&str, Branches> {
+    let (input, (left, right)) = delimited(
+        pair(tag("("), complete::space0),
+        separated_pair(
+            complete::alphanumeric1,
+            pair(tag(","), complete::space1),
+            complete::alphanumeric1,
+        ),
+        pair(complete::space0, tag(")")),
+    )(input)?;
+    let left = left.to_string();
+    let right = right.to_string();
+    Ok((input, Branches { left, right }))
+}
+
+fn parse_nodes(input: &str) -> IResult<&str, (String, Branches)> {
+    let (input, (node, branches)) = separated_pair(
+        complete::alphanumeric1,
+        tuple((complete::space1, tag("="), complete::space1)),
+        parse_branches,
+    )(input)?;
+    Ok((input, (node.to_string(), branches)))
+}
+
+fn parse_node_tree(input: &str) -> IResult<&str, BTreeMap<String, Branches>> {
+    let (input, map) = separated_list1(complete::line_ending, parse_nodes)(input)?;
+    let map = map.into_iter().collect();
+    Ok((input, map))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Network> {
+    let (input, (directions, nodes)) =
+        separated_pair(parse_directions, complete::line_ending, parse_node_tree)(input)?;
+    Ok((
+        input,
+        Network {
+            directions,
+            nodes: NodeTree(nodes),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn node_tree_to_dot() {
+        let tree = parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").nodes;
+        assert_eq!(
+            tree.to_dot(),
+            r#"digraph {
+    "AAA" [color="green"];
+    "BBB";
+    "ZZZ" [color="red"];
+    "AAA" -> "BBB" [label="L"];
+    "AAA" -> "BBB" [label="R"];
+    "BBB" -> "AAA" [label="L"];
+    "BBB" -> "ZZZ" [label="R"];
+    "ZZZ" -> "ZZZ" [label="L"];
+    "ZZZ" -> "ZZZ" [label="R"];
+}
+"#
+        );
+    }
+}
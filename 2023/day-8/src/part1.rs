@@ -1,47 +1,17 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    multi::{many1, separated_list1},
-    sequence::{delimited, pair, separated_pair, tuple},
-    IResult, Parser,
-};
-use std::collections::BTreeMap;
-
-#[derive(Debug, Copy, Clone)]
-enum Direction {
-    Left,
-    Right,
-}
-
-#[derive(Debug, Clone)]
-struct Branches {
-    pub left: String,
-    pub right: String,
-}
-
-impl Branches {
-    fn choose(&self, direction: Direction) -> &str {
-        match direction {
-            Direction::Left => &self.left,
-            Direction::Right => &self.right,
-        }
-    }
-}
+use crate::model::Network;
 
 /// day 8 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - network the parsed directions and nodes
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// panics when the directions lead to a node that isn't in the network
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (_, (steps, branches)) = parse_input(input).expect("aoc expects valid input");
+pub fn part1(network: &Network) -> String {
+    let (steps, branches) = (&network.directions, &network.nodes.0);
 
     let mut current = "AAA";
     let mut count = 0_usize;
@@ -55,54 +25,10 @@ pub fn part1(input: &str) -> String {
     count.to_string()
 }
 
-fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
-    let (input, directions) = many1(alt((
-        tag("L").map(|_| Direction::Left),
-        tag("R").map(|_| Direction::Right),
-    )))(input)?;
-    let (input, _) = complete::line_ending(input)?;
-    Ok((input, directions))
-}
-
-fn parse_branches(input: &str) -> IResult<&str, Branches> {
-    let (input, (left, right)) = delimited(
-        pair(tag("("), complete::space0),
-        separated_pair(
-            complete::alpha1,
-            pair(tag(","), complete::space1),
-            complete::alpha1,
-        ),
-        pair(complete::space0, tag(")")),
-    )(input)?;
-    let left = left.to_string();
-    let right = right.to_string();
-    Ok((input, Branches { left, right }))
-}
-
-fn parse_nodes(input: &str) -> IResult<&str, (String, Branches)> {
-    let (input, (node, branches)) = separated_pair(
-        complete::alpha1,
-        tuple((complete::space1, tag("="), complete::space1)),
-        parse_branches,
-    )(input)?;
-    Ok((input, (node.to_string(), branches)))
-}
-
-fn parse_node_tree(input: &str) -> IResult<&str, BTreeMap<String, Branches>> {
-    let (input, map) = separated_list1(complete::line_ending, parse_nodes)(input)?;
-    let map = map.into_iter().collect();
-    Ok((input, map))
-}
-
-fn parse_input(input: &str) -> IResult<&str, (Vec<Direction>, BTreeMap<String, Branches>)> {
-    let (input, x) =
-        separated_pair(parse_directions, complete::line_ending, parse_node_tree)(input)?;
-    Ok((input, x))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
     use rstest::rstest;
 
     #[rstest]
@@ -128,7 +54,7 @@ ZZZ = (ZZZ, ZZZ)",
     )]
 
     fn part1_works(#[case] input: &str, #[case] expected: &str) {
-        let result = part1(input);
+        let result = part1(&parse(input));
         assert_eq!(result, expected);
     }
 }
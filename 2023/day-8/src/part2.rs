@@ -1,46 +1,17 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete,
-    multi::{many1, separated_list1},
-    sequence::{delimited, pair, separated_pair, tuple},
-    IResult, Parser,
-};
-use std::collections::BTreeMap;
-
-#[derive(Debug, Copy, Clone)]
-enum Direction {
-    Left,
-    Right,
-}
-
-struct Branches {
-    pub left: String,
-    pub right: String,
-}
-
-impl Branches {
-    fn choose(&self, direction: Direction) -> &str {
-        match direction {
-            Direction::Left => &self.left,
-            Direction::Right => &self.right,
-        }
-    }
-}
+use crate::model::Network;
 
 /// day 8 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - network the parsed directions and nodes
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// panics when the directions lead to a node that isn't in the network
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let (_, (steps, branches)) = parse_input(input).expect("aoc expects valid input");
+pub fn part2(network: &Network) -> String {
+    let (steps, branches) = (&network.directions, &network.nodes.0);
 
     let starting_node: Vec<&str> = branches
         .keys()
@@ -88,54 +59,10 @@ fn gcd(a: usize, b: usize) -> usize {
     gcd(b, a % b)
 }
 
-fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
-    let (input, directions) = many1(alt((
-        tag("L").map(|_| Direction::Left),
-        tag("R").map(|_| Direction::Right),
-    )))(input)?;
-    let (input, _) = complete::line_ending(input)?;
-    Ok((input, directions))
-}
-
-fn parse_branches(input: &str) -> IResult<&str, Branches> {
-    let (input, (left, right)) = delimited(
-        pair(tag("("), complete::space0),
-        separated_pair(
-            complete::alphanumeric1,
-            pair(tag(","), complete::space1),
-            complete::alphanumeric1,
-        ),
-        pair(complete::space0, tag(")")),
-    )(input)?;
-    let left = left.to_string();
-    let right = right.to_string();
-    Ok((input, Branches { left, right }))
-}
-
-fn parse_nodes(input: &str) -> IResult<&str, (String, Branches)> {
-    let (input, (node, branches)) = separated_pair(
-        complete::alphanumeric1,
-        tuple((complete::space1, tag("="), complete::space1)),
-        parse_branches,
-    )(input)?;
-    Ok((input, (node.to_string(), branches)))
-}
-
-fn parse_node_tree(input: &str) -> IResult<&str, BTreeMap<String, Branches>> {
-    let (input, map) = separated_list1(complete::line_ending, parse_nodes)(input)?;
-    let map = map.into_iter().collect();
-    Ok((input, map))
-}
-
-fn parse_input(input: &str) -> IResult<&str, (Vec<Direction>, BTreeMap<String, Branches>)> {
-    let (input, x) =
-        separated_pair(parse_directions, complete::line_ending, parse_node_tree)(input)?;
-    Ok((input, x))
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
     use rstest::rstest;
 
     #[rstest]
@@ -154,7 +81,7 @@ XXX = (XXX, XXX)",
     )]
 
     fn part2_works(#[case] input: &str, #[case] expected: &str) {
-        let result = part2(input);
+        let result = part2(&parse(input));
         assert_eq!(result, expected);
     }
 }
@@ -1,3 +1,7 @@
+pub mod sequence;
+pub use crate::sequence::*;
+pub mod model;
+pub use crate::model::*;
 pub mod part1;
 pub use crate::part1::*;
 pub mod part2;
@@ -0,0 +1,23 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use nom::{character::complete, multi::separated_list1, IResult};
+
+/// day 9 oasis report of aoc 2023, one history per line
+///
+/// # Arguments
+/// - input the input for today's puzzle
+///
+/// # Panics
+/// panics when a line isn't whitespace separated whole numbers
+#[must_use]
+pub fn parse(input: &str) -> Vec<Vec<i64>> {
+    let (_, report) = parse_input(input).expect("should have valid input for aoc");
+    report
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
+    separated_list1(
+        complete::line_ending,
+        separated_list1(complete::space1, complete::i64),
+    )(input)
+}
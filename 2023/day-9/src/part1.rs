@@ -1,44 +1,32 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{character::complete, multi::separated_list1, IResult};
-use std::{iter::successors, ops::Not};
+use crate::PolynomialSequence;
 
 /// day 9 part 1 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - report the parsed histories
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// panics when a history is empty or extrapolates past an `i64`
 #[must_use]
-pub fn part1(input: &str) -> String {
-    let (_, report) = parse_input(input).expect("should have valid input for aoc");
+pub fn part1(report: &[Vec<i64>]) -> String {
     report.iter().map(|x| get_next(x)).sum::<i64>().to_string()
 }
 
 fn get_next(array: &[i64]) -> i64 {
-    let array = Vec::from(array);
-    successors(Some(array), |a| {
-        a.iter()
-            .all(|x| x == &0)
-            .not()
-            .then_some(a.windows(2).map(|a| a[1] - a[0]).collect::<Vec<_>>())
-    })
-    .map(|x| *x.last().unwrap())
-    .sum()
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
-    separated_list1(
-        complete::line_ending,
-        separated_list1(complete::space1, complete::i64),
-    )(input)
+    let sequence = PolynomialSequence::new(array).expect("every history has a value");
+    sequence
+        .forward(1)
+        .to_integer()
+        .try_into()
+        .expect("whole numbers extrapolate to a whole number")
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
     use rstest::rstest;
 
     #[rstest]
@@ -55,7 +43,7 @@ mod test {
 
     #[test]
     fn part1_works() {
-        let result = part1(INPUT);
+        let result = part1(&parse(INPUT));
         assert_eq!(result, "114".to_string());
     }
 }
@@ -1,46 +1,36 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use nom::{character::complete, multi::separated_list1, IResult};
-use std::{iter::successors, ops::Not};
+use crate::PolynomialSequence;
 
 /// day 9 part 2 of aoc 2023
 ///
 /// # Arguments
-/// - input the input for today's puzzle
+/// - report the parsed histories
 ///
 /// # Panics
-/// panics whne it cannot parse the input OR when ever the number of game numbers is greater than
-/// usize
+/// panics when a history is empty or extrapolates past an `i64`
 #[must_use]
-pub fn part2(input: &str) -> String {
-    let (_, report) = parse_input(input).expect("should have valid input for aoc");
-    report.iter().map(|x| get_next(x)).sum::<i64>().to_string()
+pub fn part2(report: &[Vec<i64>]) -> String {
+    report
+        .iter()
+        .map(|x| get_previous(x))
+        .sum::<i64>()
+        .to_string()
 }
 
-fn get_next(array: &[i64]) -> i64 {
-    let array = Vec::from(array);
-    let mut a = successors(Some(array.clone()), |a| {
-        a.iter()
-            .all(|x| x == &0)
-            .not()
-            .then_some(a.windows(2).map(|a| a[1] - a[0]).collect::<Vec<_>>())
-    })
-    .map(|x| *x.first().unwrap())
-    .collect::<Vec<_>>();
-    a.reverse();
-    a.iter().fold(0, |acc, x| x - acc)
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
-    separated_list1(
-        complete::line_ending,
-        separated_list1(complete::space1, complete::i64),
-    )(input)
+fn get_previous(array: &[i64]) -> i64 {
+    let sequence = PolynomialSequence::new(array).expect("every history has a value");
+    sequence
+        .backward(1)
+        .to_integer()
+        .try_into()
+        .expect("whole numbers extrapolate to a whole number")
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::parse;
     use rstest::rstest;
 
     #[rstest]
@@ -48,7 +38,7 @@ mod test {
     #[case(vec![1,3,6,10,15,21], 0)]
     #[case(vec![10,13,16,21,30,45], 5)]
     fn part2_next(#[case] array: Vec<i64>, #[case] expected: i64) {
-        assert_eq!(get_next(&array), expected);
+        assert_eq!(get_previous(&array), expected);
     }
 
     const INPUT: &str = "0 3 6 9 12 15
@@ -57,7 +47,7 @@ mod test {
 
     #[test]
     fn part2_works() {
-        let result = part2(INPUT);
+        let result = part2(&parse(INPUT));
         assert_eq!(result, "2".to_string());
     }
 }
@@ -0,0 +1,172 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! Sequences sampled from a polynomial at evenly spaced points, fitted with
+//! Newton's forward differences over exact rationals so extrapolating never
+//! overflows or rounds.
+
+use num::{BigInt, BigRational, One, ToPrimitive, Zero};
+
+/// The lowest degree polynomial through `samples`, with the first sample at
+/// 0, the next at 1, and so on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialSequence {
+    /// the first entry of each row of the difference table, down to the
+    /// last row that isn't all zeroes
+    differences: Vec<BigRational>,
+    len: usize,
+}
+
+impl PolynomialSequence {
+    /// Fits the samples, `None` if there aren't any.
+    #[must_use]
+    pub fn new<T: Into<BigInt> + Clone>(samples: &[T]) -> Option<Self> {
+        Self::from_rationals(
+            samples
+                .iter()
+                .map(|sample| BigRational::from_integer(sample.clone().into()))
+                .collect(),
+        )
+    }
+
+    /// Fits samples that needn't be whole numbers, `None` if there aren't any.
+    #[must_use]
+    pub fn from_rationals(samples: Vec<BigRational>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let len = samples.len();
+        let mut differences = Vec::new();
+        let mut row = samples;
+        while row.iter().any(|value| !value.is_zero()) {
+            differences.push(row[0].clone());
+            row = row.windows(2).map(|pair| &pair[1] - &pair[0]).collect();
+        }
+        Some(Self { differences, len })
+    }
+
+    /// How many samples the sequence was fitted to.
+    #[must_use]
+    pub fn sample_count(&self) -> usize {
+        self.len
+    }
+
+    /// The degree of the fitted polynomial, the all zero sequence counting
+    /// as degree 0.
+    ///
+    /// When this is `sample_count() - 1` the samples were all used up getting here
+    /// and a longer sample might well show a higher degree.
+    #[must_use]
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+
+    /// The polynomial at `x`, summing each difference times `x` choose its
+    /// row, which works just as well for `x` before the first sample.
+    #[must_use]
+    pub fn at(&self, x: i64) -> BigRational {
+        let x = BigRational::from_integer(x.into());
+        let mut choose = BigRational::one();
+        let mut total = BigRational::zero();
+        for (k, difference) in self.differences.iter().enumerate() {
+            total += difference * &choose;
+            let k = BigRational::from_integer(k.into());
+            choose = choose * (&x - &k) / (k + BigRational::one());
+        }
+        total
+    }
+
+    /// the value `steps` after the last sample
+    #[must_use]
+    pub fn forward(&self, steps: usize) -> BigRational {
+        self.at(to_x(self.len - 1 + steps))
+    }
+
+    /// the value `steps` before the first sample
+    #[must_use]
+    pub fn backward(&self, steps: usize) -> BigRational {
+        self.at(-to_x(steps))
+    }
+
+    /// [`Self::at`] when it comes out a whole number that fits in an `i64`
+    #[must_use]
+    pub fn integer_at(&self, x: i64) -> Option<i64> {
+        let value = self.at(x);
+        if value.is_integer() {
+            value.to_integer().to_i64()
+        } else {
+            None
+        }
+    }
+}
+
+fn to_x(steps: usize) -> i64 {
+    i64::try_from(steps).expect("steps fit in an i64")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![0, 3, 6, 9, 12, 15], 1)]
+    #[case(vec![1, 3, 6, 10, 15, 21], 2)]
+    #[case(vec![10, 13, 16, 21, 30, 45], 3)]
+    #[case(vec![7, 7, 7], 0)]
+    #[case(vec![0, 0], 0)]
+    #[case(vec![5], 0)]
+    fn finds_degree(#[case] samples: Vec<i64>, #[case] degree: usize) {
+        let sequence = PolynomialSequence::new(&samples).unwrap();
+        assert_eq!(sequence.degree(), degree);
+        for (x, sample) in samples.iter().enumerate() {
+            assert_eq!(sequence.integer_at(to_x(x)), Some(*sample));
+        }
+    }
+
+    #[test]
+    fn extrapolates_both_ways() {
+        // x^3 - 2x + 5 from x = -3
+        let f = |x: i64| x.pow(3) - 2 * x + 5;
+        let samples = (-3..3).map(f).collect::<Vec<_>>();
+        let sequence = PolynomialSequence::new(&samples).unwrap();
+        assert_eq!(sequence.degree(), 3);
+        for steps in 1..20 {
+            let after = i64::try_from(steps).unwrap() + 2;
+            let before = -3 - i64::try_from(steps).unwrap();
+            assert_eq!(
+                sequence.forward(steps),
+                BigRational::from_integer(f(after).into())
+            );
+            assert_eq!(
+                sequence.backward(steps),
+                BigRational::from_integer(f(before).into())
+            );
+        }
+    }
+
+    #[test]
+    fn stays_exact_past_i64() {
+        let samples = (0..4_i64)
+            .map(|x| x.pow(3) * 1_000_000_000)
+            .collect::<Vec<_>>();
+        let sequence = PolynomialSequence::new(&samples).unwrap();
+        let far = sequence.at(10_000_000);
+        assert_eq!(
+            far,
+            BigRational::from_integer(BigInt::from(10_000_000_i64).pow(3) * 1_000_000_000)
+        );
+        assert_eq!(sequence.integer_at(10_000_000), None);
+    }
+
+    #[test]
+    fn fits_rationals() {
+        // x^2 / 2, which is a half off a whole number at every odd x
+        let samples = (0..4)
+            .map(|x| BigRational::new(BigInt::from(x * x), BigInt::from(2)))
+            .collect();
+        let sequence = PolynomialSequence::from_rationals(samples).unwrap();
+        assert_eq!(sequence.degree(), 2);
+        assert_eq!(sequence.at(5), BigRational::new(25.into(), 2.into()));
+        assert_eq!(sequence.integer_at(5), None);
+        assert_eq!(sequence.integer_at(6), Some(18));
+    }
+}
@@ -1,3 +1,7 @@
+pub(crate) mod locations;
+pub use crate::locations::*;
+pub(crate) mod model;
+pub use crate::model::*;
 pub(crate) mod part1;
 pub use crate::part1::*;
 pub(crate) mod part2;
@@ -0,0 +1,188 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+/// The lists of location IDs, one per column of the input, each kept sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    lists: Vec<Vec<u64>>,
+}
+
+impl LocationLists {
+    #[must_use]
+    pub fn new(mut lists: Vec<Vec<u64>>) -> Self {
+        for list in &mut lists {
+            list.sort_unstable();
+        }
+        Self { lists }
+    }
+
+    /// Turns rows of IDs into the lists down each column, as long as every
+    /// row has the same number of IDs.
+    #[must_use]
+    pub fn from_rows(rows: Vec<Vec<u64>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let mut lists = vec![Vec::with_capacity(rows.len()); width];
+        for row in rows {
+            for (list, id) in lists.iter_mut().zip(row) {
+                list.push(id);
+            }
+        }
+        Some(Self::new(lists))
+    }
+
+    #[must_use]
+    pub fn lists(&self) -> &[Vec<u64>] {
+        &self.lists
+    }
+
+    /// each distinct ID in the list and how many times it shows up, smallest
+    /// first
+    #[must_use]
+    pub fn histogram(&self, list: usize) -> Histogram<'_> {
+        Histogram {
+            rest: self.lists.get(list).map_or(&[], Vec::as_slice),
+        }
+    }
+
+    /// how many times `id` shows up in the list
+    #[must_use]
+    pub fn frequency(&self, list: usize, id: u64) -> u64 {
+        self.lists.get(list).map_or(0, |list| {
+            let start = list.partition_point(|x| *x < id);
+            let end = list.partition_point(|x| *x <= id);
+            (end - start) as u64
+        })
+    }
+
+    /// Pairs the smallest IDs of every list, then the next smallest and so
+    /// on, adding up how far apart each set is. Lists longer than the
+    /// shortest one have their largest IDs left out.
+    #[must_use]
+    pub fn distance(&self) -> u64 {
+        let len = self.lists.iter().map(Vec::len).min().unwrap_or(0);
+        (0..len)
+            .map(|rank| {
+                let (low, high) = self
+                    .lists
+                    .iter()
+                    .map(|list| list[rank])
+                    .fold((u64::MAX, 0), |(low, high), id| (low.min(id), high.max(id)));
+                high - low
+            })
+            .sum()
+    }
+
+    /// Each ID in the first list times how many times it shows up in every
+    /// other list, worked out by walking the histograms together in order.
+    #[must_use]
+    pub fn similarity(&self) -> u64 {
+        if self.lists.is_empty() {
+            return 0;
+        }
+        let mut runs = (0..self.lists.len())
+            .map(|list| self.histogram(list).peekable())
+            .collect::<Vec<_>>();
+        let mut total = 0;
+        loop {
+            let (mut low, mut high, mut product) = (u64::MAX, 0, 1);
+            for run in &mut runs {
+                let Some(&(id, count)) = run.peek() else {
+                    return total;
+                };
+                low = low.min(id);
+                high = high.max(id);
+                product *= count;
+            }
+            if low == high {
+                total += low * product;
+            }
+            // anything behind the largest ID can't be in every list
+            for run in &mut runs {
+                run.next_if(|(id, _)| low == high || *id < high);
+            }
+        }
+    }
+}
+
+/// The runs of equal IDs in a sorted list.
+#[derive(Debug, Clone)]
+pub struct Histogram<'a> {
+    rest: &'a [u64],
+}
+
+impl Iterator for Histogram<'_> {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = *self.rest.first()?;
+        let count = self.rest.partition_point(|x| *x == id);
+        self.rest = &self.rest[count..];
+        Some((id, count as u64))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::model::parse;
+
+    const INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3 ";
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn counts_ids() {
+        let lists = parse(INPUT).unwrap();
+        assert_eq!(
+            lists.lists(),
+            &[vec![1, 2, 3, 3, 3, 4], vec![3, 3, 3, 4, 5, 9]]
+        );
+        assert_eq!(
+            lists.histogram(1).collect::<Vec<_>>(),
+            vec![(3, 3), (4, 1), (5, 1), (9, 1)]
+        );
+        assert_eq!(lists.frequency(0, 3), 3);
+        assert_eq!(lists.frequency(0, 5), 0);
+        assert_eq!(lists.frequency(2, 3), 0);
+        assert_eq!(lists.histogram(2).next(), None);
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn compares_many_lists() {
+        let lists = parse("3 4 3\n4 3 1\n2 5 3\n1 3 4").unwrap();
+        // 1 2 3 4 / 3 3 4 5 / 1 3 3 4
+        assert_eq!(lists.distance(), 2 + 1 + 1 + 1);
+        // 3 is in the others 2 and 2 times, 4 once each
+        assert_eq!(lists.similarity(), 3 * 2 * 2 + 4);
+        assert!(parse("1 2\n3").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_counting(rows in prop::collection::vec([0_u64..8, 0_u64..8], 0..20)) {
+            let rows = rows.into_iter().map(Vec::from).collect::<Vec<_>>();
+            let lists = LocationLists::from_rows(rows.clone()).unwrap();
+            let mut counts = HashMap::new();
+            for row in &rows {
+                *counts.entry(row[1]).or_insert(0) += 1;
+            }
+            prop_assert_eq!(
+                lists.similarity(),
+                rows.iter()
+                    .map(|row| row[0] * counts.get(&row[0]).unwrap_or(&0))
+                    .sum::<u64>()
+            );
+        }
+    }
+}
@@ -1,5 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_1::parse;
 use day_1::part1;
 use day_1::part2;
 
@@ -12,10 +15,8 @@ static ALLOC: dhat::Alloc = dhat::Alloc;
 
 #[derive(Debug, Error)]
 enum Day1Error {
-    #[error("Part 1 failed")]
-    Part1Error,
-    #[error("Part 2 failed")]
-    Part2Error,
+    #[error("Parsing failed")]
+    ParseError,
 }
 
 fn main() -> Result<(), Day1Error> {
@@ -23,9 +24,14 @@ fn main() -> Result<(), Day1Error> {
     let _profiler = dhat::Profiler::new_heap();
 
     let input = include_str!("./input.txt");
-    let part1_result = part1(input).change_context(Day1Error::Part1Error)?;
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input).change_context(Day1Error::Part2Error)?;
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let lists = parse(input).change_context(Day1Error::ParseError)?;
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&lists);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&lists);
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
     Ok(())
 }
@@ -0,0 +1,35 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::{Report, Result, ResultExt};
+use nom::{character::complete, combinator::map_opt, multi::separated_list1, IResult};
+use thiserror::Error;
+
+use crate::locations::LocationLists;
+
+// day-1
+#[derive(Debug, Error)]
+pub enum Day1ModelError {
+    #[error("Problem parsing Day 1")]
+    ParseError,
+}
+
+/// Parses the Day-1 input into the lists down each column
+///
+/// # Errors
+/// - `ParseError` there was an issue with the parser
+pub fn parse(input: &str) -> Result<LocationLists, Day1ModelError> {
+    let (_, lists) = parse_input(input)
+        .map_err(|x| Report::from(x.to_owned()))
+        .change_context(Day1ModelError::ParseError)?;
+    Ok(lists)
+}
+
+fn parse_input(input: &str) -> IResult<&str, LocationLists> {
+    map_opt(
+        separated_list1(
+            complete::line_ending,
+            separated_list1(complete::space1, complete::u64),
+        ),
+        LocationLists::from_rows,
+    )(input)
+}
@@ -0,0 +1,34 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::{Report, Result, ResultExt};
+use nom::{character::complete, multi::separated_list1, IResult};
+use thiserror::Error;
+
+use crate::report::XmasReport;
+
+// day-2
+#[derive(Debug, Error)]
+pub enum Day2ModelError {
+    #[error("Problem parsing Day 2")]
+    ParseError,
+}
+
+/// Parses the Day-2 input into its reports
+///
+/// # Errors
+/// - `ParseError` there was an issue with the parser
+pub fn parse(input: &str) -> Result<Vec<XmasReport>, Day2ModelError> {
+    let (_, reports) = parse_input(input)
+        .map_err(|x| Report::from(x.to_owned()))
+        .change_context(Day2ModelError::ParseError)?;
+    Ok(reports)
+}
+
+pub(crate) fn parse_level(input: &str) -> IResult<&str, XmasReport> {
+    let (input, v) = separated_list1(complete::space1, complete::u32)(input)?;
+    Ok((input, XmasReport { levels: v }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<XmasReport>> {
+    separated_list1(complete::line_ending, parse_level)(input)
+}
@@ -1,89 +1,22 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use error_stack::{Report, Result, ResultExt};
-use itertools::Itertools;
-use nom::{character::complete, multi::separated_list1, IResult};
-use std::cmp::Ordering;
-use thiserror::Error;
-
-#[derive(Debug, PartialEq, Eq)]
-enum Safety {
-    Safe,
-    UnSafe,
-}
-
-#[derive(Debug, PartialEq, Eq)]
-struct XmasReport {
-    levels: Vec<u32>,
-}
-
-impl XmasReport {
-    pub fn is_safe(&self) -> Safety {
-        fn internal_check(levels: &[u32]) -> Safety {
-            let mut dir = Ordering::Equal;
-            for (a, b) in levels.iter().tuple_windows() {
-                if !(1_u32..=3).contains(&(a.abs_diff(*b))) {
-                    return Safety::UnSafe;
-                }
-                let new_dir = a.cmp(b);
-                if dir != Ordering::Equal && dir != new_dir {
-                    return Safety::UnSafe;
-                }
-                dir = new_dir;
-            }
-            Safety::Safe
-        }
-        let ret = internal_check(&self.levels);
-        if ret == Safety::Safe {
-            return ret;
-        }
-        for i in 0..self.levels.len() {
-            let mut attempt = self.levels.clone();
-            let _ = attempt.remove(i);
-            let ret = internal_check(&attempt);
-            if ret == Safety::Safe {
-                return ret;
-            }
-        }
-        Safety::UnSafe
-    }
-}
-
-// day-2
-#[derive(Debug, Error)]
-pub enum Day2Part2Error {
-    #[error("Problem parsing Day 2")]
-    ParseError,
-}
+use crate::{Safety, XmasReport, STEPS};
 
 /// Day-2 Part 2 for 2024 advent of code
 /// Problem can be found here: <https://adventofcode.com/2024/day/2#part2>
-///
-/// # Errors
-/// - `ParseError` there was an issue with the parser
-pub fn part2(input: &str) -> Result<String, Day2Part2Error> {
-    let (_, reports) = parse_input(input)
-        .map_err(|x| Report::from(x.to_owned()))
-        .change_context(Day2Part2Error::ParseError)?;
-    Ok(reports
+#[must_use]
+pub fn part2(reports: &[XmasReport]) -> String {
+    reports
         .iter()
-        .filter(|x| x.is_safe() == Safety::Safe)
+        .filter(|x| x.safety(&STEPS, 1) == Safety::Safe)
         .count()
-        .to_string())
-}
-
-fn parse_level(input: &str) -> IResult<&str, XmasReport> {
-    let (input, v) = separated_list1(complete::space1, complete::u32)(input)?;
-    Ok((input, XmasReport { levels: v }))
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<XmasReport>> {
-    separated_list1(complete::line_ending, parse_level)(input)
+        .to_string()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{parse, parse_level};
     use rstest::rstest;
 
     #[rstest]
@@ -98,7 +31,7 @@ mod test {
     #[case("8 6 4 6 4", Safety::UnSafe)]
     fn part1_report_safety(#[case] input: &str, #[case] expected: Safety) {
         let (_, tester) = parse_level(input).expect("should be valid input");
-        assert_eq!(tester.is_safe(), expected);
+        assert_eq!(tester.safety(&STEPS, 1), expected);
     }
 
     const INPUT: &str = "7 6 4 2 1
@@ -111,7 +44,7 @@ mod test {
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part2_works() {
-        let result = part2(INPUT).unwrap();
+        let result = part2(&parse(INPUT).unwrap());
         assert_eq!(result, "4".to_string());
     }
 }
@@ -0,0 +1,158 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::ops::RangeInclusive;
+
+/// how far apart neighbouring levels may be in the puzzle
+pub const STEPS: RangeInclusive<u32> = 1..=3;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Safety {
+    Safe,
+    UnSafe,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct XmasReport {
+    pub levels: Vec<u32>,
+}
+
+impl XmasReport {
+    /// Whether the report is safe with at most `removals` levels taken out.
+    #[must_use]
+    pub fn safety(&self, steps: &RangeInclusive<u32>, removals: usize) -> Safety {
+        if self.dampen(steps, removals).is_some() {
+            Safety::Safe
+        } else {
+            Safety::UnSafe
+        }
+    }
+
+    /// The fewest levels to take out so every step left goes the same way
+    /// and is within `steps`, as long as that is no more than `removals`.
+    ///
+    /// For each direction, `kept[i]` is the fewest removals leaving a safe
+    /// run that ends by keeping level `i`. Anything more than `removals`
+    /// back from `i` would need too many removals between it and `i`, so
+    /// each level only looks that far back and the whole thing is linear in
+    /// the length of the report.
+    #[must_use]
+    pub fn dampen(&self, steps: &RangeInclusive<u32>, removals: usize) -> Option<Vec<usize>> {
+        let len = self.levels.len();
+        if len == 0 {
+            return Some(Vec::new());
+        }
+        [true, false]
+            .into_iter()
+            .filter_map(|increasing| {
+                let fits = |a: u32, b: u32| {
+                    (if increasing { a <= b } else { a >= b }) && steps.contains(&a.abs_diff(b))
+                };
+                // (removals so far, previous kept level), dropping everything
+                // before always being an option
+                let mut kept: Vec<(usize, Option<usize>)> = Vec::with_capacity(len);
+                for i in 0..len {
+                    let best = (i.saturating_sub(removals + 1)..i)
+                        .filter(|&j| fits(self.levels[j], self.levels[i]))
+                        .map(|j| (kept[j].0 + (i - j - 1), Some(j)))
+                        .fold(
+                            (i, None),
+                            |best, from| if from.0 < best.0 { from } else { best },
+                        );
+                    kept.push(best);
+                }
+                let (count, last) = (0..len).map(|i| (kept[i].0 + (len - 1 - i), i)).min()?;
+                (count <= removals).then(|| {
+                    let mut keep = vec![false; len];
+                    let mut at = Some(last);
+                    while let Some(i) = at {
+                        keep[i] = true;
+                        at = kept[i].1;
+                    }
+                    (0..len).filter(|&i| !keep[i]).collect::<Vec<_>>()
+                })
+            })
+            .min_by_key(Vec::len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::parse_level;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    /// tries taking out every combination of up to `removals` levels
+    fn brute_force(levels: &[u32], steps: &RangeInclusive<u32>, removals: usize) -> Option<usize> {
+        let safe = |levels: &[u32]| {
+            levels
+                .windows(2)
+                .all(|w| w[0] <= w[1] && steps.contains(&w[0].abs_diff(w[1])))
+                || levels
+                    .windows(2)
+                    .all(|w| w[0] >= w[1] && steps.contains(&w[0].abs_diff(w[1])))
+        };
+        (0..1_u32 << levels.len())
+            .filter(|mask| mask.count_ones() as usize <= removals)
+            .filter(|mask| {
+                let rest = levels
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| mask & (1 << i) == 0)
+                    .map(|(_, level)| *level)
+                    .collect::<Vec<_>>();
+                safe(&rest)
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+    }
+
+    #[rstest]
+    #[case("7 6 4 2 1", 0, Some(vec![]))]
+    #[case("1 3 2 4 5", 1, Some(vec![2]))]
+    #[case("8 6 4 4 1", 1, Some(vec![3]))]
+    #[case("1 2 7 8 9", 1, None)]
+    #[case("1 2 7 8 9", 2, Some(vec![0, 1]))]
+    #[case("9 1 2 3 4", 1, Some(vec![0]))]
+    #[case("1 2 3 4 9", 1, Some(vec![4]))]
+    #[case("5", 0, Some(vec![]))]
+    fn removes_levels(
+        #[case] input: &str,
+        #[case] removals: usize,
+        #[case] expected: Option<Vec<usize>>,
+    ) {
+        let (_, report) = parse_level(input).unwrap();
+        assert_eq!(report.dampen(&STEPS, removals), expected);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2_000))]
+
+        #[test]
+        fn matches_brute_force(
+            levels in prop::collection::vec(0_u32..12, 1..10),
+            low in 0_u32..2,
+            high in 1_u32..5,
+            removals in 0_usize..4,
+        ) {
+            let steps = low..=high;
+            let report = XmasReport {
+                levels: levels.clone(),
+            };
+            let removed = report.dampen(&steps, removals);
+            prop_assert_eq!(
+                removed.as_ref().map(Vec::len),
+                brute_force(&levels, &steps, removals)
+            );
+            if let Some(removed) = removed {
+                let rest = levels
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| !removed.contains(i))
+                    .map(|(_, level)| *level)
+                    .collect::<Vec<_>>();
+                prop_assert_eq!(brute_force(&rest, &steps, 0), Some(0));
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::{Report, Result, ResultExt};
+use thiserror::Error;
+
+use crate::tokenizer::{Scanner, Token};
+
+// day-3
+#[derive(Debug, Error)]
+pub enum Day3ModelError {
+    #[error("Problem parsing Day 3")]
+    ParseError,
+}
+
+/// Scans the Day-3 corrupted memory for its instructions
+///
+/// # Errors
+/// - `ParseError` there was an issue reading the input
+pub fn parse(input: &str) -> Result<Vec<Token>, Day3ModelError> {
+    Scanner::new(input.as_bytes())
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(Report::from)
+        .change_context(Day3ModelError::ParseError)
+}
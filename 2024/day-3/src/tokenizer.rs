@@ -0,0 +1,297 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::io::{self, BufReader, Bytes, Read};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// An instruction found in the corrupted memory and the byte it starts at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+/// How far into an instruction the scanner is, holding where it started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    /// matched this many bytes of `"mul("`
+    Mul(usize, usize),
+    /// the first number, and how many digits it has
+    Left(usize, u32, u8),
+    /// the first number and then the second
+    Right(usize, u32, u32, u8),
+    /// matched this many bytes of `"do"`, which both `do()` and `don't()`
+    /// start with
+    Do(usize, usize),
+    /// matched this many bytes of `"do()"`
+    Enable(usize, usize),
+    /// matched this many bytes of `"don't()"`
+    Disable(usize, usize),
+}
+
+const MUL: &[u8] = b"mul(";
+const ENABLE: &[u8] = b"do()";
+const DISABLE: &[u8] = b"don't()";
+
+/// Picks the instructions out of a stream of corrupted memory a byte at a
+/// time, without holding on to any more of it than the instruction it is
+/// part way through.
+///
+/// None of the instructions have an `m` or `d` anywhere but the front, so
+/// when a byte doesn't fit, starting again from that byte is enough to not
+/// miss anything.
+pub struct Scanner<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    offset: usize,
+    state: State,
+}
+
+impl<R: Read> Scanner<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            bytes: BufReader::new(reader).bytes(),
+            offset: 0,
+            state: State::Start,
+        }
+    }
+
+    /// Moves the state along by one byte, giving back an instruction if that
+    /// byte finished one.
+    fn step(&mut self, byte: u8, at: usize) -> Option<Token> {
+        let (state, done) = match (self.state, byte) {
+            (State::Mul(start, 4), b'0'..=b'9') => {
+                (State::Left(start, u32::from(byte - b'0'), 1), None)
+            }
+            (State::Mul(start, n), _) if n < 4 && MUL[n] == byte => {
+                (State::Mul(start, n + 1), None)
+            }
+            (State::Left(start, a, n), b'0'..=b'9') if n < 3 => (
+                State::Left(start, a * 10 + u32::from(byte - b'0'), n + 1),
+                None,
+            ),
+            (State::Left(start, a, _), b',') => (State::Right(start, a, 0, 0), None),
+            (State::Right(start, a, b, n), b'0'..=b'9') if n < 3 => (
+                State::Right(start, a, b * 10 + u32::from(byte - b'0'), n + 1),
+                None,
+            ),
+            (State::Right(start, a, b, n), b')') if n > 0 => (
+                State::Start,
+                Some(Token {
+                    offset: start,
+                    instruction: Instruction::Mul(a, b),
+                }),
+            ),
+            (State::Do(start, n), _) if n < 2 && ENABLE[n] == byte => {
+                (State::Do(start, n + 1), None)
+            }
+            (State::Do(start, 2), b'(') => (State::Enable(start, 3), None),
+            (State::Do(start, 2), b'n') => (State::Disable(start, 3), None),
+            (State::Enable(start, n), _) if ENABLE[n] == byte => {
+                if n + 1 == ENABLE.len() {
+                    (
+                        State::Start,
+                        Some(Token {
+                            offset: start,
+                            instruction: Instruction::Do,
+                        }),
+                    )
+                } else {
+                    (State::Enable(start, n + 1), None)
+                }
+            }
+            (State::Disable(start, n), _) if DISABLE[n] == byte => {
+                if n + 1 == DISABLE.len() {
+                    (
+                        State::Start,
+                        Some(Token {
+                            offset: start,
+                            instruction: Instruction::Dont,
+                        }),
+                    )
+                } else {
+                    (State::Disable(start, n + 1), None)
+                }
+            }
+            (State::Start, b'm') => (State::Mul(at, 1), None),
+            (State::Start, b'd') => (State::Do(at, 1), None),
+            (State::Start, _) => (State::Start, None),
+            // the byte broke the instruction, see if it starts a new one
+            _ => {
+                self.state = State::Start;
+                return self.step(byte, at);
+            }
+        };
+        self.state = state;
+        done
+    }
+}
+
+impl<R: Read> Iterator for Scanner<R> {
+    type Item = io::Result<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let byte = match self.bytes.next()? {
+                Ok(byte) => byte,
+                Err(e) => return Some(Err(e)),
+            };
+            let at = self.offset;
+            self.offset += 1;
+            if let Some(token) = self.step(byte, at) {
+                return Some(Ok(token));
+            }
+        }
+    }
+}
+
+/// What running the instructions came to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Run {
+    pub sum: u64,
+    /// the instructions that did nothing, in the order they came
+    pub ignored: Vec<Token>,
+}
+
+/// Adds up the products, and with `conditionals` lets `do()` and `don't()`
+/// turn them on and off. Without them it is the `do()`s and `don't()`s that
+/// get ignored.
+pub fn execute(tokens: impl IntoIterator<Item = Token>, conditionals: bool) -> Run {
+    let mut enabled = true;
+    let mut run = Run::default();
+    for token in tokens {
+        match token.instruction {
+            Instruction::Mul(a, b) if enabled => run.sum += u64::from(a) * u64::from(b),
+            Instruction::Do | Instruction::Dont if conditionals => {
+                enabled = token.instruction == Instruction::Do;
+            }
+            _ => {
+                log::trace!("ignoring {token:?}");
+                run.ignored.push(token);
+            }
+        }
+    }
+    run
+}
+
+/// [`execute`]s the instructions as they are read, stopping at the first
+/// thing that goes wrong reading.
+///
+/// # Errors
+/// when the reader fails
+pub fn run(reader: impl Read, conditionals: bool) -> io::Result<Run> {
+    let mut error = None;
+    let run = execute(
+        Scanner::new(reader).map_while(|token| token.map_err(|e| error = Some(e)).ok()),
+        conditionals,
+    );
+    error.map_or(Ok(run), Err)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        Scanner::new(input.as_bytes()).map(Result::unwrap).collect()
+    }
+
+    fn mul(offset: usize, a: u32, b: u32) -> Token {
+        Token {
+            offset,
+            instruction: Instruction::Mul(a, b),
+        }
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn finds_instructions() {
+        assert_eq!(
+            tokens("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"),
+            vec![
+                mul(1, 2, 4),
+                Token {
+                    offset: 20,
+                    instruction: Instruction::Dont
+                },
+                mul(28, 5, 5),
+                mul(48, 11, 8),
+                Token {
+                    offset: 59,
+                    instruction: Instruction::Do
+                },
+                mul(64, 8, 5),
+            ]
+        );
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn restarts_on_a_broken_instruction() {
+        assert_eq!(tokens("mmul(1,2)"), vec![mul(1, 1, 2)]);
+        assert_eq!(tokens("mul(1,mul(3,4)"), vec![mul(6, 3, 4)]);
+        assert_eq!(
+            tokens("dodo()"),
+            vec![Token {
+                offset: 2,
+                instruction: Instruction::Do
+            }]
+        );
+        assert_eq!(
+            tokens("don'do()"),
+            vec![Token {
+                offset: 4,
+                instruction: Instruction::Do
+            }]
+        );
+        assert_eq!(tokens("mul(1234,5)mul(123,)mul(,1)mul ( 1,2)"), vec![]);
+        assert_eq!(tokens("mul(999,1)"), vec![mul(0, 999, 1)]);
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn reports_ignored_instructions() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let with = run(input.as_bytes(), true).unwrap();
+        assert_eq!(with.sum, 48);
+        assert_eq!(with.ignored, vec![mul(28, 5, 5), mul(48, 11, 8)]);
+        let without = run(input.as_bytes(), false).unwrap();
+        assert_eq!(without.sum, 161);
+        assert_eq!(
+            without.ignored.iter().map(|t| t.offset).collect::<Vec<_>>(),
+            vec![20, 59]
+        );
+    }
+
+    /// hands the input over a byte at a time
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(slot)) => {
+                    *slot = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn reads_streams() {
+        let input = "don't()mul(2,3)do()mul(4,5)".repeat(10_000);
+        let streamed = run(Trickle(input.as_bytes()), true).unwrap();
+        assert_eq!(streamed, run(input.as_bytes(), true).unwrap());
+        assert_eq!(streamed.sum, 200_000);
+        assert_eq!(streamed.ignored.len(), 10_000);
+        assert_eq!(streamed.ignored[9_999].offset, 27 * 9_999 + 7);
+    }
+}
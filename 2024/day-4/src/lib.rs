@@ -1,3 +1,7 @@
+pub mod word_search;
+pub use crate::word_search::*;
+pub mod model;
+pub use crate::model::*;
 pub mod part1;
 pub use crate::part1::*;
 pub mod part2;
@@ -0,0 +1,26 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::{Report, Result};
+use thiserror::Error;
+
+use crate::word_search::Grid;
+
+// day-4
+#[derive(Debug, Error)]
+pub enum Day4ModelError {
+    #[error("Problem parsing Day 4")]
+    ParseError,
+}
+
+/// Reads the Day-4 word search into a grid
+///
+/// # Errors
+/// - `ParseError` the rows weren't all the same length
+pub fn parse(input: &str) -> Result<Grid, Day4ModelError> {
+    let grid = Grid::parse(input);
+    if !grid.is_rectangular() {
+        return Err(Report::new(Day4ModelError::ParseError)
+            .attach_printable("the word search should be a rectangle"));
+    }
+    Ok(grid)
+}
@@ -1,94 +1,18 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use error_stack::Result;
-use glam::IVec2;
-use thiserror::Error;
+use crate::word_search::Grid;
 
-// day-4
-#[derive(Debug, Error)]
-pub enum Day4Part1Error {
-    #[error("Problem parsing Day 4")]
-    ParseError,
-}
-
-#[allow(clippy::cast_sign_loss)]
 /// Day-4 Part 1 for 2024 advent of code
 /// Problem can be found here: <https://adventofcode.com/2024/day/4>
-///
-/// # Errors
-/// - `ParseError` there was an issue with the parser
-///
-/// # Panics
-/// - If there is a catastropic error as it only panics in event that a lenght is negative
-pub fn part1(input: &str) -> Result<String, Day4Part1Error> {
-    //read in grid
-    let grid = input
-        .lines()
-        .map(|line| Vec::from(line.as_bytes()))
-        .collect::<Vec<_>>();
-    let num_of_rows = grid
-        .len()
-        .try_into()
-        .expect("length cannot be negative ever");
-    let num_of_cols = grid[0].len().try_into().unwrap(); //because we know it will be rectangular
-                                                         //window over each letter (skip over not x's
-    let total: usize = grid
-        .iter()
-        .enumerate()
-        .map(|(row_num, row)| {
-            row.iter()
-                .enumerate()
-                .map(|(col_num, col)| {
-                    if *col == b'X' {
-                        //window over the rest
-                        let point =
-                            IVec2::new(row_num.try_into().unwrap(), col_num.try_into().unwrap());
-                        [
-                            IVec2::NEG_X,
-                            IVec2::NEG_ONE,
-                            IVec2::NEG_Y,
-                            IVec2::new(1, -1),
-                            IVec2::X,
-                            IVec2::ONE,
-                            IVec2::Y,
-                            IVec2::new(-1, 1),
-                        ]
-                        .iter()
-                        .filter(|dir| {
-                            let extent = point + (*dir * 3);
-                            if extent.x < 0
-                                || extent.x >= num_of_rows
-                                || extent.y < 0
-                                || extent.y >= num_of_cols
-                            {
-                                return false;
-                            }
-                            let m = point + *dir;
-                            let a = point + 2 * *dir;
-                            let s = point + 3 * *dir;
-                            grid.get(m.x as u32 as usize)
-                                .map(|g| g.get(m.y as u32 as usize))
-                                .unwrap();
-                            grid[m.x as u32 as usize][m.y as u32 as usize] == b'M'
-                                && grid[a.x as u32 as usize][a.y as u32 as usize] == b'A'
-                                && grid[s.x as u32 as usize][s.y as u32 as usize] == b'S'
-                        })
-                        .count()
-                        //todo!("at pos {row_num} - {col_num}")
-                    } else {
-                        0_usize
-                    }
-                })
-                .sum::<usize>()
-        })
-        .sum();
-    //count
-    Ok(total.to_string())
+#[must_use]
+pub fn part1(grid: &Grid) -> String {
+    grid.find_word("XMAS").len().to_string()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "MMMSXXMASM
 MSAMXMSMSA
@@ -104,7 +28,7 @@ MXMXAXMASX";
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part1_works() {
-        let result = part1(INPUT).unwrap();
+        let result = part1(&parse(INPUT).unwrap());
         assert_eq!(result, "18".to_string());
     }
 }
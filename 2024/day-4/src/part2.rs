@@ -1,85 +1,19 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use error_stack::Result;
-use glam::IVec2;
-use thiserror::Error;
-
-// day-4
-#[derive(Debug, Error)]
-pub enum Day4Part2Error {
-    #[error("Problem parsing Day 4")]
-    ParseError,
-}
+use crate::word_search::{Grid, Stencil};
 
 /// Day-4 Part 2 for 2024 advent of code
 /// Problem can be found here: <https://adventofcode.com/2024/day/4#part2>
-///
-/// # Errors
-/// - `ParseError` there was an issue with the parser
-///
-/// # Panics
-/// - If there is a catastropic error as it only panics in event that a lenght is negative
-pub fn part2(input: &str) -> Result<String, Day4Part2Error> {
-    //read in grid
-    let grid = input
-        .lines()
-        .map(|line| Vec::from(line.as_bytes()))
-        .collect::<Vec<_>>();
-    let num_of_rows = grid.len();
-    let num_of_cols = grid[0].len();
-    //window over each letter (skip over not x's
-    let total: usize = grid
-        .iter()
-        .enumerate()
-        .skip(1)
-        .take(num_of_rows - 2)
-        .map(|(row_num, row)| {
-            row.iter()
-                .enumerate()
-                .skip(1)
-                .take(num_of_cols - 2)
-                .map(|(col_num, col)| {
-                    if *col == b'A' {
-                        //window over the rest
-                        let point =
-                            IVec2::new(row_num.try_into().unwrap(), col_num.try_into().unwrap());
-                        let up_forward = point + IVec2::new(-1, 1);
-                        let up_back = point + IVec2::NEG_ONE;
-                        let down_forward = point + IVec2::ONE;
-                        let down_back = point + IVec2::new(1, -1);
-                        #[allow(clippy::cast_sign_loss)]
-                        (((grid[up_back.x as u32 as usize][up_back.y as u32 as usize] == b'M'
-                            && grid[down_forward.x as u32 as usize]
-                                [down_forward.y as u32 as usize]
-                                == b'S')
-                            || (grid[up_back.x as u32 as usize][up_back.y as u32 as usize] == b'S'
-                                && grid[down_forward.x as u32 as usize]
-                                    [down_forward.y as u32 as usize]
-                                    == b'M'))
-                            && ((grid[down_back.x as u32 as usize][down_back.y as u32 as usize]
-                                == b'M'
-                                && grid[up_forward.x as u32 as usize]
-                                    [up_forward.y as u32 as usize]
-                                    == b'S')
-                                || (grid[down_back.x as u32 as usize][down_back.y as u32 as usize]
-                                    == b'S'
-                                    && grid[up_forward.x as u32 as usize]
-                                        [up_forward.y as u32 as usize]
-                                        == b'M'))).into()
-                    } else {
-                        0_usize
-                    }
-                })
-                .sum::<usize>()
-        })
-        .sum();
-    //count
-    Ok(total.to_string())
+#[must_use]
+pub fn part2(grid: &Grid) -> String {
+    let cross = Stencil::parse("M.S\n.A.\nM.S");
+    grid.find_any(&cross.symmetries()).len().to_string()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "MMMSXXMASM
 MSAMXMSMSA
@@ -95,7 +29,7 @@ MXMXAXMASX";
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part2_works() {
-        let result = part2(INPUT).unwrap();
+        let result = part2(&parse(INPUT).unwrap());
         assert_eq!(result, "9".to_string());
     }
 }
@@ -0,0 +1,296 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashSet;
+
+use glam::IVec2;
+
+/// every way a word can run through the grid
+pub const DIRECTIONS: [IVec2; 8] = [
+    IVec2::X,
+    IVec2::ONE,
+    IVec2::Y,
+    IVec2::new(-1, 1),
+    IVec2::NEG_X,
+    IVec2::NEG_ONE,
+    IVec2::NEG_Y,
+    IVec2::new(1, -1),
+];
+
+/// Letters at offsets from wherever the pattern is placed, anything not
+/// listed being allowed to be any letter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stencil {
+    pub cells: Vec<(IVec2, u8)>,
+}
+
+impl Stencil {
+    /// the word running from the origin in `direction`
+    #[must_use]
+    pub fn word(word: &str, direction: IVec2) -> Self {
+        Self {
+            cells: word
+                .bytes()
+                .zip(0..)
+                .map(|(letter, i)| (direction * i, letter))
+                .collect(),
+        }
+    }
+
+    /// A pattern drawn out in rows, with `.` for any letter.
+    #[must_use]
+    pub fn parse(pattern: &str) -> Self {
+        Self {
+            cells: pattern
+                .lines()
+                .zip(0..)
+                .flat_map(|(line, y)| {
+                    line.bytes()
+                        .zip(0..)
+                        .filter(|(letter, _)| *letter != b'.')
+                        .map(move |(letter, x)| (IVec2::new(x, y), letter))
+                })
+                .collect(),
+        }
+    }
+
+    /// moved so its top left is the origin, with the cells in order, so
+    /// stencils that are the same shape compare equal
+    fn normalized(mut self) -> Self {
+        let corner = self
+            .cells
+            .iter()
+            .map(|(offset, _)| *offset)
+            .reduce(IVec2::min)
+            .unwrap_or(IVec2::ZERO);
+        for (offset, _) in &mut self.cells {
+            *offset -= corner;
+        }
+        self.cells.sort_by_key(|(offset, _)| (offset.y, offset.x));
+        self
+    }
+
+    /// The distinct stencils from turning this one by quarter turns and
+    /// flipping it over.
+    #[must_use]
+    pub fn symmetries(&self) -> Vec<Self> {
+        let mut seen = HashSet::new();
+        (0..8)
+            .map(|n| {
+                let cells = self
+                    .cells
+                    .iter()
+                    .map(|(offset, letter)| {
+                        let flipped = if n >= 4 {
+                            IVec2::new(-offset.x, offset.y)
+                        } else {
+                            *offset
+                        };
+                        let turned = (0..n % 4).fold(flipped, |o, _| IVec2::new(-o.y, o.x));
+                        (turned, *letter)
+                    })
+                    .collect();
+                Self { cells }.normalized()
+            })
+            .filter(|stencil| seen.insert(stencil.clone()))
+            .collect()
+    }
+}
+
+/// Where one placement of a stencil sits in the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// the grid cells the stencil's letters landed on, as column and row
+    pub cells: Vec<IVec2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    rows: Vec<Vec<u8>>,
+}
+
+impl Grid {
+    #[must_use]
+    pub fn parse(input: &str) -> Self {
+        Self {
+            rows: input.lines().map(|line| line.as_bytes().to_vec()).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, at: IVec2) -> Option<u8> {
+        let x = usize::try_from(at.x).ok()?;
+        let y = usize::try_from(at.y).ok()?;
+        self.rows.get(y)?.get(x).copied()
+    }
+
+    fn positions(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.rows.iter().zip(0..).flat_map(|(row, y)| {
+            (0..i32::try_from(row.len()).unwrap_or(i32::MAX)).map(move |x| IVec2::new(x, y))
+        })
+    }
+
+    /// every placement of the stencil where all its letters line up
+    #[must_use]
+    pub fn find(&self, stencil: &Stencil) -> Vec<Match> {
+        self.positions()
+            .filter(|at| {
+                stencil
+                    .cells
+                    .iter()
+                    .all(|(offset, letter)| self.get(*at + *offset) == Some(*letter))
+            })
+            .map(|at| Match {
+                cells: stencil
+                    .cells
+                    .iter()
+                    .map(|(offset, _)| at + *offset)
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Every placement of any of the stencils, a set of cells only being
+    /// counted once however many stencils land on it.
+    #[must_use]
+    pub fn find_any(&self, stencils: &[Stencil]) -> Vec<Match> {
+        let mut seen = HashSet::new();
+        stencils
+            .iter()
+            .flat_map(|stencil| self.find(stencil))
+            .filter(|found| {
+                let mut cells = found.cells.iter().map(|c| (c.y, c.x)).collect::<Vec<_>>();
+                cells.sort_unstable();
+                seen.insert(cells)
+            })
+            .collect()
+    }
+
+    /// every run of `word` in any of the eight directions
+    #[must_use]
+    pub fn find_word(&self, word: &str) -> Vec<Match> {
+        let stencils = DIRECTIONS
+            .iter()
+            .map(|direction| Stencil::word(word, *direction))
+            .collect::<Vec<_>>();
+        self.find_any(&stencils)
+    }
+
+    /// The grid with only the letters that are part of a match left in,
+    /// the rest shown as `.` like the puzzle does.
+    #[must_use]
+    pub fn render(&self, matches: &[Match]) -> String {
+        let used = matches
+            .iter()
+            .flat_map(|found| found.cells.iter().copied())
+            .collect::<HashSet<_>>();
+        self.rows
+            .iter()
+            .zip(0..)
+            .map(|(row, y)| {
+                row.iter()
+                    .zip(0..)
+                    .map(|(letter, x)| {
+                        if used.contains(&IVec2::new(x, y)) {
+                            char::from(*letter)
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// whether every row is as long as the first
+    #[must_use]
+    pub fn is_rectangular(&self) -> bool {
+        self.rows
+            .windows(2)
+            .all(|pair| pair[0].len() == pair[1].len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn highlights_words() {
+        let grid = Grid::parse(INPUT);
+        let found = grid.find_word("XMAS");
+        assert_eq!(found.len(), 18);
+        assert_eq!(
+            grid.render(&found),
+            "....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX"
+        );
+        assert!(found.contains(&Match {
+            cells: vec![
+                IVec2::new(5, 0),
+                IVec2::new(6, 0),
+                IVec2::new(7, 0),
+                IVec2::new(8, 0)
+            ]
+        }));
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn highlights_stencils() {
+        let grid = Grid::parse(INPUT);
+        let cross = Stencil::parse("M.S\n.A.\nM.S");
+        let found = grid.find_any(&cross.symmetries());
+        assert_eq!(found.len(), 9);
+        assert_eq!(
+            grid.render(&found),
+            ".M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+.........."
+        );
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn counts_symmetries() {
+        assert_eq!(Stencil::parse("M.S\n.A.\nM.S").symmetries().len(), 4);
+        assert_eq!(Stencil::parse("M.M\n.A.\nM.M").symmetries().len(), 1);
+        assert_eq!(Stencil::parse("AB\nC.").symmetries().len(), 8);
+        assert_eq!(Stencil::word("XMAS", IVec2::X).symmetries().len(), 4);
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn palindromes_count_once() {
+        let grid = Grid::parse("ABA\n...");
+        assert_eq!(grid.find_word("ABA").len(), 1);
+        assert_eq!(grid.find_word("A").len(), 2);
+    }
+}
@@ -1,5 +1,8 @@
 #![warn(clippy::all, clippy::pedantic)]
 
+use std::time::Instant;
+
+use day_5::parse;
 use day_5::part1;
 use day_5::part2;
 
@@ -12,8 +15,8 @@ static ALLOC: dhat::Alloc = dhat::Alloc;
 
 #[derive(Debug, Error)]
 enum Day5Error {
-    #[error("Part 1 failed")]
-    Part1Error,
+    #[error("Parsing failed")]
+    ParseError,
     #[error("Part 2 failed")]
     Part2Error,
 }
@@ -23,9 +26,14 @@ fn main() -> Result<(), Day5Error> {
     let _profiler = dhat::Profiler::new_heap();
 
     let input = include_str!("./input.txt");
-    let part1_result = part1(input).change_context(Day5Error::Part1Error)?;
-    println!("part 1: {part1_result}");
-    let part2_result = part2(input).change_context(Day5Error::Part2Error)?;
-    println!("part 2: {part2_result}");
+    let start = Instant::now();
+    let manual = parse(input).change_context(Day5Error::ParseError)?;
+    println!("parsed in {:?}", start.elapsed());
+    let start = Instant::now();
+    let part1_result = part1(&manual);
+    println!("part 1: {part1_result} ({:?})", start.elapsed());
+    let start = Instant::now();
+    let part2_result = part2(&manual).change_context(Day5Error::Part2Error)?;
+    println!("part 2: {part2_result} ({:?})", start.elapsed());
     Ok(())
 }
@@ -0,0 +1,68 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use error_stack::{Report, Result, ResultExt};
+use nom::{
+    bytes::complete::tag, character::complete, multi::separated_list1, sequence::separated_pair,
+    IResult,
+};
+use thiserror::Error;
+
+// day-5
+#[derive(Debug, Error)]
+pub enum Day5ModelError {
+    #[error("Problem parsing Day 5")]
+    ParseError,
+}
+
+/// every page and the pages that have to come after it
+pub type Orderings = HashMap<u32, Vec<u32>>;
+
+/// The page ordering rules and the updates to check against them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafetyManual {
+    pub orderings: Orderings,
+    pub updates: Vec<Vec<u32>>,
+}
+
+/// Parses the Day-5 input into its rules and updates
+///
+/// # Errors
+/// - `ParseError` there was an issue with the parser
+pub fn parse(input: &str) -> Result<SafetyManual, Day5ModelError> {
+    let (_, (orderings, updates)) = parse_input(input)
+        .map_err(|x| Report::from(x.to_owned()))
+        .change_context(Day5ModelError::ParseError)?;
+    Ok(SafetyManual { orderings, updates })
+}
+
+fn parse_ordering(input: &str) -> IResult<&str, Orderings> {
+    let (input, rules) = separated_list1(
+        complete::line_ending,
+        separated_pair(complete::u32, tag("|"), complete::u32),
+    )(input)?;
+    let ordering = rules
+        .iter()
+        .fold(HashMap::new(), |mut acc: Orderings, (a, b)| {
+            acc.entry(*a).or_default().push(*b);
+            acc
+        });
+    Ok((input, ordering))
+}
+
+fn parse_update(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(tag(","), complete::u32)(input)
+}
+
+fn parse_updates(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+    separated_list1(complete::line_ending, parse_update)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Orderings, Vec<Vec<u32>>)> {
+    let (input, ordering) = parse_ordering(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, updates) = parse_updates(input)?;
+    Ok((input, (ordering, updates)))
+}
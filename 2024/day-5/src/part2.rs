@@ -1,92 +1,117 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-use std::{cmp::Ordering, collections::HashMap};
+use std::collections::{HashMap, HashSet};
 
-use error_stack::{Report, Result, ResultExt};
-use nom::{bytes::complete::tag, character::complete, multi::separated_list1, sequence::separated_pair, IResult};
+use error_stack::{Report, Result};
 use thiserror::Error;
 
+use crate::model::{Orderings, SafetyManual};
+
 // day-5
 #[derive(Debug, Error)]
-pub enum Day5Part2Error{
-    #[error("Problem parsing Day 5")]
-    ParseError,
+pub enum Day5Part2Error {
+    #[error("The rules for an update contain a cycle")]
+    OrderCycle,
+    #[error("The rules for an update allow more than one order")]
+    AmbiguousOrder,
+    #[error("An update has the same page in it more than once")]
+    RepeatedPage,
 }
 
-type Orderings = HashMap<u32, Vec<u32>>;
-
 /// Day-5 Part 2 for 2024 advent of code
 /// Problem can be found here: <https://adventofcode.com/2024/day/5#part2>
 ///
 /// # Errors
-/// - `ParseError` there was an issue with the parser
-pub fn part2 (input: &str) -> Result<String, Day5Part2Error> {
-    let (_, (ordering, mut updates)) = parse_input(input)
-        .map_err(|x| Report::from(x.to_owned()))
-        .change_context(Day5Part2Error::ParseError)?;
-    let middles: u32 = updates
-        .iter_mut()
-        .filter_map(|update| {
+/// - `OrderCycle` the rules between an update's pages go round in a circle
+/// - `AmbiguousOrder` the rules don't pin down one order for an update
+/// - `RepeatedPage` an update lists a page more than once
+pub fn part2(manual: &SafetyManual) -> Result<String, Day5Part2Error> {
+    let ordering = &manual.orderings;
+    let middles: u32 = manual
+        .updates
+        .iter()
+        .filter(|update| {
             let update_len = update.len();
             for i in 0..update_len {
                 let before = &update[..i];
                 if let Some(a) = update.get(i) {
                     if let Some(rules) = ordering.get(a) {
                         if rules.iter().any(|b| before.contains(b)) {
-                            return Some(update);
+                            return true;
                         }
                     }
                 }
             }
-            None
+            false
         })
         .map(|update| {
-            update.sort_by(|a,b| {
-                let Some(rule_a) = ordering.get(a) else { return Ordering::Equal;} ;
-                //let Some(rule_b) = ordering.get(b) else { return Ordering::Equal;} ;
-                if rule_a.contains(b) {
-                    return Ordering::Less;
-                }
-                Ordering::Equal
-            });
-            update[update.len()/2]
+            let ordered = induced_order(ordering, update)?;
+            Ok(ordered[ordered.len() / 2])
         })
-        .sum();
+        .sum::<Result<u32, Day5Part2Error>>()?;
     Ok(middles.to_string())
 }
 
-fn parse_ordering(input: &str) -> IResult<&str, Orderings> {
-    let (input, rules) = separated_list1(
-        complete::line_ending,
-        separated_pair(complete::u32, tag("|"), complete::u32),
-    )(input)?;
-    let ordering = rules.iter().fold(HashMap::new(), |mut acc: Orderings, (a, b)| {
-        acc.entry(*a).or_default().push(*b);
-        acc
-    });
-    Ok((input, ordering))
-}
-
-fn parse_update(input: &str) -> IResult<&str, Vec<u32>> {
-    separated_list1(tag(","), complete::u32)(input)
-}
-
-fn parse_updates(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
-    separated_list1(complete::line_ending, parse_update)(input)
-}
-
-fn parse_input(input: &str) -> IResult<&str, (Orderings, Vec<Vec<u32>>)> {
-    let (input, ordering) = parse_ordering(input)?;
-    let (input, _) = complete::line_ending(input)?;
-    let (input, _) = complete::line_ending(input)?;
-    let (input, updates) = parse_updates(input)?;
-    Ok((input, (ordering, updates)))
+/// Orders the update's pages by Kahn's algorithm over only the rules between
+/// them, which has exactly one answer when a single page is ready at every
+/// step.
+///
+/// # Errors
+/// - `OrderCycle` some pages are never ready as they wait on each other
+/// - `AmbiguousOrder` more than one page was ready at once
+/// - `RepeatedPage` a page shows up more than once, so it can't have one place
+fn induced_order(ordering: &Orderings, update: &[u32]) -> Result<Vec<u32>, Day5Part2Error> {
+    let mut pages = HashSet::new();
+    if let Some(page) = update.iter().find(|page| !pages.insert(**page)) {
+        return Err(Report::new(Day5Part2Error::RepeatedPage)
+            .attach_printable(format!("{page} is repeated in {update:?}")));
+    }
+    let after = |page: &u32| {
+        ordering
+            .get(page)
+            .into_iter()
+            .flatten()
+            .filter(|later| update.contains(later))
+    };
+    let mut waiting_on = update
+        .iter()
+        .map(|page| (*page, 0_usize))
+        .collect::<HashMap<_, _>>();
+    for later in update.iter().flat_map(after) {
+        *waiting_on.entry(*later).or_default() += 1;
+    }
+    let mut ready = update
+        .iter()
+        .filter(|page| waiting_on[*page] == 0)
+        .copied()
+        .collect::<Vec<_>>();
+    let mut ordered = Vec::with_capacity(update.len());
+    while let Some(page) = ready.pop() {
+        if !ready.is_empty() {
+            return Err(Report::new(Day5Part2Error::AmbiguousOrder)
+                .attach_printable(format!("{page} and {ready:?} could go next in {update:?}")));
+        }
+        ordered.push(page);
+        for later in after(&page) {
+            let count = waiting_on.get_mut(later).expect("every page is counted");
+            *count -= 1;
+            if *count == 0 {
+                ready.push(*later);
+            }
+        }
+    }
+    if ordered.len() != update.len() {
+        return Err(Report::new(Day5Part2Error::OrderCycle)
+            .attach_printable(format!("only {ordered:?} of {update:?} could be ordered")));
+    }
+    log::trace!("{update:?} orders as {ordered:?}");
+    Ok(ordered)
 }
 
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse;
 
     const INPUT: &str = "47|53
 97|13
@@ -117,11 +142,61 @@ mod test {
 61,13,29
 97,13,75,29,47";
 
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn orders_by_the_induced_rules() {
+        let ordering = parse(INPUT).unwrap().orderings;
+        assert_eq!(
+            induced_order(&ordering, &[97, 13, 75, 29, 47]).unwrap(),
+            vec![97, 75, 47, 29, 13]
+        );
+        assert_eq!(
+            induced_order(&ordering, &[61, 13, 29]).unwrap(),
+            vec![61, 29, 13]
+        );
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn rejects_cycles() {
+        let ordering = parse("1|2\n2|3\n3|1\n3|4\n\n4,3,2,1").unwrap().orderings;
+        let error = induced_order(&ordering, &[4, 3, 2, 1]).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            Day5Part2Error::OrderCycle
+        ));
+        // a cycle through a page outside the update doesn't count
+        assert_eq!(induced_order(&ordering, &[3, 2, 4]).unwrap(), vec![2, 3, 4]);
+        assert!(part2(&parse("1|2\n2|1\n\n1,2\n2,1").unwrap()).is_err());
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn rejects_ambiguous_orders() {
+        let ordering = parse("1|2\n1|3\n\n3,2,1").unwrap().orderings;
+        let error = induced_order(&ordering, &[3, 2, 1]).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            Day5Part2Error::AmbiguousOrder
+        ));
+        assert_eq!(induced_order(&ordering, &[2, 1]).unwrap(), vec![1, 2]);
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn rejects_repeated_pages() {
+        let ordering = parse("1|2\n2|3\n\n2,1,2,3").unwrap().orderings;
+        let error = induced_order(&ordering, &[2, 1, 2, 3]).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            Day5Part2Error::RepeatedPage
+        ));
+    }
+
     #[test_log::test]
     #[test_log(default_log_filter = "trace")]
     fn part2_works() {
-        let result = part2(INPUT).unwrap();
+        let result = part2(&parse(INPUT).unwrap()).unwrap();
         assert_eq!(result, "123".to_string());
     }
 }
-
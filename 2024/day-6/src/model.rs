@@ -0,0 +1,321 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::{collections::HashSet, ops::Sub};
+
+use error_stack::{Report, Result};
+use glam::IVec2;
+use thiserror::Error;
+
+// day-6
+#[derive(Debug, Error)]
+pub enum Day6ModelError {
+    #[error("Problem parsing Day 6")]
+    ParseError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl From<&Direction> for IVec2 {
+    fn from(value: &Direction) -> Self {
+        match value {
+            Direction::North => IVec2::NEG_X,
+            Direction::East => IVec2::Y,
+            Direction::South => IVec2::X,
+            Direction::West => IVec2::NEG_Y,
+        }
+    }
+}
+
+impl Sub<&Direction> for &IVec2 {
+    type Output = IVec2;
+
+    fn sub(self, rhs: &Direction) -> Self::Output {
+        self - IVec2::from(rhs)
+    }
+}
+
+impl Direction {
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+}
+
+/// How a guard's walk finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkEnd {
+    /// The guard walked off the map, stepping onto this position.
+    Exits(IVec2),
+    /// The guard came back to a corner facing the same way, so walks forever.
+    Loops,
+}
+
+#[derive(Debug)]
+pub struct Walk {
+    /// Where the guard started, every position they turned at, and the last
+    /// position on the map if they left it.
+    pub corners: Vec<IVec2>,
+    pub end: WalkEnd,
+}
+
+impl Walk {
+    #[must_use]
+    pub fn visited(&self) -> HashSet<IVec2> {
+        let mut visited = HashSet::from_iter(self.corners.first().copied());
+        for leg in self.corners.windows(2) {
+            let step = (leg[1] - leg[0]).signum();
+            let mut pos = leg[0];
+            while pos != leg[1] {
+                pos += step;
+                visited.insert(pos);
+            }
+        }
+        visited
+    }
+}
+
+/// The obstacles on the map, also indexed by row and by column in sorted
+/// order so the next one in any direction is a binary search away.
+#[derive(Debug, Clone)]
+pub struct MyMap {
+    pub obstacles: HashSet<IVec2>,
+    rows: Vec<Vec<i32>>,
+    cols: Vec<Vec<i32>>,
+    pub height: u32,
+    pub width: u32,
+}
+
+impl MyMap {
+    #[must_use]
+    pub fn new(obstacles: HashSet<IVec2>, height: u32, width: u32) -> Self {
+        let mut map = Self {
+            obstacles: HashSet::new(),
+            rows: vec![Vec::new(); height as usize],
+            cols: vec![Vec::new(); width as usize],
+            height,
+            width,
+        };
+        for obstacle in obstacles {
+            map.add_obstacle(obstacle);
+        }
+        map
+    }
+
+    /// The row and column of `pos` if it is on the map.
+    fn index(&self, pos: IVec2) -> Option<(usize, usize)> {
+        let row = usize::try_from(pos.x)
+            .ok()
+            .filter(|x| *x < self.rows.len())?;
+        let col = usize::try_from(pos.y)
+            .ok()
+            .filter(|y| *y < self.cols.len())?;
+        Some((row, col))
+    }
+
+    /// Adds an obstacle to the map, returns `false` if it was off the map or
+    /// already there.
+    pub fn add_obstacle(&mut self, pos: IVec2) -> bool {
+        let Some((row, col)) = self.index(pos) else {
+            return false;
+        };
+        if !self.obstacles.insert(pos) {
+            return false;
+        }
+        let row = &mut self.rows[row];
+        row.insert(row.partition_point(|y| *y < pos.y), pos.y);
+        let col = &mut self.cols[col];
+        col.insert(col.partition_point(|x| *x < pos.x), pos.x);
+        true
+    }
+
+    /// Removes an obstacle from the map, returns `false` if it wasn't there.
+    pub fn remove_obstacle(&mut self, pos: IVec2) -> bool {
+        let Some((row, col)) = self.index(pos) else {
+            return false;
+        };
+        if !self.obstacles.remove(&pos) {
+            return false;
+        }
+        let row = &mut self.rows[row];
+        row.remove(row.partition_point(|y| *y < pos.y));
+        let col = &mut self.cols[col];
+        col.remove(col.partition_point(|x| *x < pos.x));
+        true
+    }
+
+    /// The first obstacle from `start_pos` going in `direction`.
+    #[must_use]
+    pub fn next_obstacle(&self, start_pos: IVec2, direction: Direction) -> Option<IVec2> {
+        let (row, col) = self.index(start_pos)?;
+        match direction {
+            Direction::North => {
+                let col = &self.cols[col];
+                let i = col.partition_point(|x| *x < start_pos.x);
+                i.checked_sub(1).map(|i| IVec2::new(col[i], start_pos.y))
+            }
+            Direction::South => {
+                let col = &self.cols[col];
+                let i = col.partition_point(|x| *x <= start_pos.x);
+                col.get(i).map(|x| IVec2::new(*x, start_pos.y))
+            }
+            Direction::East => {
+                let row = &self.rows[row];
+                let i = row.partition_point(|y| *y <= start_pos.y);
+                row.get(i).map(|y| IVec2::new(start_pos.x, *y))
+            }
+            Direction::West => {
+                let row = &self.rows[row];
+                let i = row.partition_point(|y| *y < start_pos.y);
+                i.checked_sub(1).map(|i| IVec2::new(start_pos.x, row[i]))
+            }
+        }
+    }
+
+    /// The last position on the map going from `pos` in `direction`.
+    fn edge(&self, pos: IVec2, direction: Direction) -> IVec2 {
+        match direction {
+            Direction::North => IVec2::new(0, pos.y),
+            Direction::East => IVec2::new(pos.x, self.width.cast_signed() - 1),
+            Direction::South => IVec2::new(self.height.cast_signed() - 1, pos.y),
+            Direction::West => IVec2::new(pos.x, 0),
+        }
+    }
+
+    /// Walks the guard from `start` until they leave the map or start going
+    /// round in circles.
+    #[must_use]
+    pub fn walk(&self, start: IVec2, direction: Direction) -> Walk {
+        let mut corners = vec![start];
+        let mut turns = HashSet::new();
+        let mut pos = start;
+        let mut direction = direction;
+        loop {
+            let Some(obstacle) = self.next_obstacle(pos, direction) else {
+                let last = self.edge(pos, direction);
+                corners.push(last);
+                return Walk {
+                    corners,
+                    end: WalkEnd::Exits(last + IVec2::from(&direction)),
+                };
+            };
+            pos = &obstacle - &direction;
+            if !turns.insert((pos, direction)) {
+                return Walk {
+                    corners,
+                    end: WalkEnd::Loops,
+                };
+            }
+            corners.push(pos);
+            direction = direction.next();
+        }
+    }
+}
+
+/// The map of the lab and where the guard starts out on it.
+#[derive(Debug, Clone)]
+pub struct Lab {
+    pub guard: IVec2,
+    pub map: MyMap,
+}
+
+/// Parses the Day-6 map and finds the guard on it
+///
+/// # Errors
+/// - `ParseError` there was no guard on the map or it was too big
+pub fn parse(input: &str) -> Result<Lab, Day6ModelError> {
+    let (guard, map) = parse_input(input).ok_or_else(|| Report::new(Day6ModelError::ParseError))?;
+    Ok(Lab { guard, map })
+}
+
+fn parse_input(input: &str) -> Option<(IVec2, MyMap)> {
+    let mut guard = None;
+    let mut obstacles = HashSet::new();
+    let mut height = 0;
+    let mut width = 0;
+    for (row_no, row) in input.lines().enumerate() {
+        height = u32::try_from(row_no).ok()? + 1;
+        width = u32::try_from(row.len()).ok()?;
+        for (col_no, c) in row.chars().enumerate() {
+            let pos = IVec2::new(i32::try_from(row_no).ok()?, i32::try_from(col_no).ok()?);
+            match c {
+                '#' => {
+                    obstacles.insert(pos);
+                }
+                '^' => guard = Some(pos),
+                _ => {}
+            }
+        }
+    }
+    Some((guard?, MyMap::new(obstacles, height, width)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn next_obstacle_works() {
+        let (guard, map) = parse_input(INPUT).unwrap();
+        assert_eq!(
+            map.next_obstacle(guard, Direction::North),
+            Some(IVec2::new(0, 4))
+        );
+        assert_eq!(map.next_obstacle(guard, Direction::East), None);
+        assert_eq!(map.next_obstacle(guard, Direction::South), None);
+        assert_eq!(
+            map.next_obstacle(guard, Direction::West),
+            Some(IVec2::new(6, 1))
+        );
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn walk_exits() {
+        let (guard, map) = parse_input(INPUT).unwrap();
+        let walk = map.walk(guard, Direction::North);
+        assert_eq!(walk.end, WalkEnd::Exits(IVec2::new(10, 7)));
+    }
+
+    #[test_log::test]
+    #[test_log(default_log_filter = "trace")]
+    fn walk_loops_with_extra_obstacle() {
+        let (guard, mut map) = parse_input(INPUT).unwrap();
+        let visited = map.walk(guard, Direction::North).visited();
+        let mut loops = visited
+            .into_iter()
+            .filter(|pos| *pos != guard)
+            .filter(|pos| {
+                map.add_obstacle(*pos);
+                let end = map.walk(guard, Direction::North).end;
+                map.remove_obstacle(*pos);
+                end == WalkEnd::Loops
+            })
+            .map(|pos| (pos.x, pos.y))
+            .collect::<Vec<_>>();
+        loops.sort_unstable();
+        assert_eq!(loops, [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]);
+    }
+}
@@ -0,0 +1,234 @@
+#![warn(clippy::all, clippy::pedantic)]
+//! Writes the graphs puzzles are built on out in graphviz's DOT format, so
+//! they can be looked at with `dot -Tsvg`.
+//!
+//! The years only pull this in as a path dependency, so its tests don't run
+//! with theirs, run them from this directory with `cargo test --all-features`.
+
+use std::{fmt::Write as _, fs, io, path::PathBuf};
+
+/// A node in a DOT graph along with the attributes to draw it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub id: String,
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl Node {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            attributes: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn attribute(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.attributes.push((key, value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn label(self, label: impl Into<String>) -> Self {
+        self.attribute("label", label)
+    }
+
+    #[must_use]
+    pub fn shape(self, shape: impl Into<String>) -> Self {
+        self.attribute("shape", shape)
+    }
+}
+
+/// An edge in a DOT graph along with the attributes to draw it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl Edge {
+    pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            attributes: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn attribute(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.attributes.push((key, value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn label(self, label: impl Into<String>) -> Self {
+        self.attribute("label", label)
+    }
+}
+
+/// Anything that can be drawn as a graph.
+///
+/// Only the nodes and edges need giving, the DOT text is built from those.
+/// Edges can name nodes that aren't listed, graphviz draws them plain.
+pub trait ToDot {
+    /// Whether this is a `digraph` or an undirected `graph`.
+    fn directed(&self) -> bool {
+        true
+    }
+
+    fn dot_nodes(&self) -> Vec<Node>;
+
+    fn dot_edges(&self) -> Vec<Edge>;
+
+    fn to_dot(&self) -> String {
+        let (kind, arrow) = if self.directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let mut dot = format!("{kind} {{\n");
+        for node in self.dot_nodes() {
+            let _ = writeln!(
+                dot,
+                "    {}{};",
+                quote(&node.id),
+                attributes(&node.attributes)
+            );
+        }
+        for edge in self.dot_edges() {
+            let _ = writeln!(
+                dot,
+                "    {} {arrow} {}{};",
+                quote(&edge.from),
+                quote(&edge.to),
+                attributes(&edge.attributes)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// # Errors
+    /// when the file can't be written
+    fn write_dot(&self, path: impl Into<PathBuf>) -> io::Result<()> {
+        fs::write(path.into(), self.to_dot())
+    }
+}
+
+/// Quotes an id or attribute, with newlines becoming graphviz line breaks.
+fn quote(id: &str) -> String {
+    let escaped = id
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+fn attributes(attributes: &[(&'static str, String)]) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let list = attributes
+        .iter()
+        .map(|(key, value)| format!("{key}={}", quote(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(" [{list}]")
+}
+
+/// The file given after `--dot` on the command line, which the runners
+/// write the day's graph to.
+#[must_use]
+pub fn path_from_args() -> Option<PathBuf> {
+    std::env::args()
+        .skip_while(|arg| arg != "--dot")
+        .nth(1)
+        .map(PathBuf::from)
+}
+
+#[cfg(feature = "petgraph")]
+impl<N, E, Ty, Ix> ToDot for petgraph::Graph<N, E, Ty, Ix>
+where
+    N: std::fmt::Display,
+    E: std::fmt::Display,
+    Ty: petgraph::EdgeType,
+    Ix: petgraph::graph::IndexType,
+{
+    fn directed(&self) -> bool {
+        self.is_directed()
+    }
+
+    fn dot_nodes(&self) -> Vec<Node> {
+        self.node_indices()
+            .map(|i| Node::new(i.index().to_string()).label(self[i].to_string()))
+            .collect()
+    }
+
+    fn dot_edges(&self) -> Vec<Edge> {
+        self.edge_indices()
+            .filter_map(|i| {
+                let (from, to) = self.edge_endpoints(i)?;
+                Some(
+                    Edge::new(from.index().to_string(), to.index().to_string())
+                        .label(self[i].to_string()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Triangle;
+
+    impl ToDot for Triangle {
+        fn dot_nodes(&self) -> Vec<Node> {
+            vec![Node::new("a").shape("box"), Node::new("b\"c").label("b\nc")]
+        }
+
+        fn dot_edges(&self) -> Vec<Edge> {
+            vec![
+                Edge::new("a", "b\"c").label("1"),
+                Edge::new("b\"c", "d"),
+                Edge::new("d", "a"),
+            ]
+        }
+    }
+
+    #[test]
+    fn writes_dot() {
+        assert_eq!(
+            Triangle.to_dot(),
+            r#"digraph {
+    "a" [shape="box"];
+    "b\"c" [label="b\nc"];
+    "a" -> "b\"c" [label="1"];
+    "b\"c" -> "d";
+    "d" -> "a";
+}
+"#
+        );
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn writes_petgraph() {
+        let mut graph = petgraph::graph::UnGraph::<&str, u32>::default();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b, 1);
+        assert_eq!(
+            graph.to_dot(),
+            r#"graph {
+    "0" [label="a"];
+    "1" [label="b"];
+    "0" -- "1" [label="1"];
+}
+"#
+        );
+    }
+}
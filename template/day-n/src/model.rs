@@ -0,0 +1,19 @@
+#![warn(clippy::all, clippy::pedantic)]
+
+use error_stack::Result;
+use thiserror::Error;
+
+// {{project-name}}
+#[derive(Debug, Error)]
+pub enum {{ project-name | upper_camel_case }}ModelError {
+    #[error("Problem parsing {{ project-name | title_case }}")]
+    ParseError,
+}
+
+/// Parses the {{ project-name | title_case }} input
+///
+/// # Errors
+/// - `ParseError` there was an issue with the parser
+pub fn parse(input: &str) -> Result<Vec<&str>, {{ project-name | upper_camel_case }}ModelError> {
+    Ok(input.lines().collect())
+}